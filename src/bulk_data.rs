@@ -0,0 +1,229 @@
+use crate::client::error::{JsonPath, ScryfallClientError};
+use futures::stream::{self, Stream, TryStreamExt};
+use serde::de::{self, DeserializeOwned};
+use serde_derive::{Deserialize, Serialize};
+use std::sync::Arc;
+use url::Url;
+use uuid::Uuid;
+
+/// The kind of a bulk export.
+///
+/// Scryfall may add new exports; anything not listed here is kept as
+/// [`BulkDataType::Unknown`] instead of failing to deserialize.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum BulkDataType {
+    /// One card object per Oracle ID, using the most recognisable printing.
+    OracleCards,
+    /// One card object per unique illustration.
+    UniqueArtwork,
+    /// Every card in English, or in the printed language if not printed in English.
+    DefaultCards,
+    /// Every card object in every language.
+    AllCards,
+    /// Every ruling; download as [`crate::Ruling`] rather than [`crate::Card`].
+    Rulings,
+    Unknown(String),
+}
+
+impl BulkDataType {
+    /// The `type` of the export, e.g. `"oracle_cards"`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            BulkDataType::OracleCards => "oracle_cards",
+            BulkDataType::UniqueArtwork => "unique_artwork",
+            BulkDataType::DefaultCards => "default_cards",
+            BulkDataType::AllCards => "all_cards",
+            BulkDataType::Rulings => "rulings",
+            BulkDataType::Unknown(type_) => type_,
+        }
+    }
+
+    /// The path segment used by `/bulk-data/:type`, e.g. `"oracle-cards"`.
+    pub fn path(&self) -> String {
+        self.as_str().replace('_', "-")
+    }
+}
+
+impl From<String> for BulkDataType {
+    fn from(type_: String) -> Self {
+        match type_.as_str() {
+            "oracle_cards" => BulkDataType::OracleCards,
+            "unique_artwork" => BulkDataType::UniqueArtwork,
+            "default_cards" => BulkDataType::DefaultCards,
+            "all_cards" => BulkDataType::AllCards,
+            "rulings" => BulkDataType::Rulings,
+            _ => BulkDataType::Unknown(type_),
+        }
+    }
+}
+
+impl From<BulkDataType> for String {
+    fn from(type_: BulkDataType) -> Self {
+        match type_ {
+            BulkDataType::Unknown(type_) => type_,
+            type_ => type_.as_str().to_string(),
+        }
+    }
+}
+
+/// A daily export of Scryfall's data, see `ScryfallClient::bulk_data_download`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BulkData {
+    pub id: Uuid,
+    /// always "bulk_data"
+    pub object: String,
+    #[serde(rename = "type")]
+    pub type_: BulkDataType,
+    pub updated_at: String, //convert to date using external crate??
+    /// link to this object on scryfall's API
+    pub uri: Url,
+    pub name: String,
+    pub description: String,
+    /// size of the file in bytes
+    pub size: u64,
+    pub download_uri: Url,
+    pub content_type: String,
+    pub content_encoding: String,
+}
+
+/// Incrementally splits a JSON array of objects into its elements.
+///
+/// Bytes can be fed in arbitrarily sized chunks; only the element currently
+/// being read is kept in memory, so multi-hundred-MB bulk files can be
+/// decoded one card at a time.
+#[derive(Debug, Default)]
+pub struct JsonArrayDecoder {
+    buf: Vec<u8>,
+    /// next byte of `buf` to scan
+    pos: usize,
+    /// start of the element being scanned, if any
+    start: Option<usize>,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    opened: bool,
+    closed: bool,
+}
+
+impl JsonArrayDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends the next chunk of the array.
+    pub fn push(&mut self, chunk: &[u8]) {
+        // drop everything before the current element so the buffer stays small
+        let keep_from = self.start.unwrap_or(self.pos);
+        if keep_from > 0 {
+            self.buf.drain(..keep_from);
+            self.pos -= keep_from;
+            self.start = self.start.map(|start| start - keep_from);
+        }
+        self.buf.extend_from_slice(chunk);
+    }
+
+    /// Decodes the next complete element, or returns `None` if more input
+    /// is needed or the array has ended.
+    pub fn next_element<T: DeserializeOwned>(&mut self) -> Option<Result<T, ScryfallClientError>> {
+        while self.pos < self.buf.len() {
+            let byte = self.buf[self.pos];
+            self.pos += 1;
+            if self.in_string {
+                match byte {
+                    _ if self.escaped => self.escaped = false,
+                    b'\\' => self.escaped = true,
+                    b'"' => self.in_string = false,
+                    _ => {}
+                }
+                continue;
+            }
+            match byte {
+                b' ' | b'\t' | b'\r' | b'\n' => {}
+                b'[' if !self.opened => self.opened = true,
+                _ if !self.opened || self.closed => {
+                    return Some(Err(malformed(byte)));
+                }
+                b',' if self.depth == 0 => {}
+                b']' if self.depth == 0 => self.closed = true,
+                b'{' | b'[' => {
+                    if self.depth == 0 {
+                        self.start = Some(self.pos - 1);
+                    }
+                    self.depth += 1;
+                }
+                b'}' | b']' if self.depth > 0 => {
+                    self.depth -= 1;
+                    if self.depth == 0 {
+                        let start = self.start.take().unwrap();
+                        let element = &self.buf[start..self.pos];
+                        return Some(decode_element(element));
+                    }
+                }
+                b'"' if self.depth > 0 => self.in_string = true,
+                _ if self.depth > 0 => {}
+                _ => return Some(Err(malformed(byte))),
+            }
+        }
+        None
+    }
+
+    /// Reports an error if the input ended before the array was closed.
+    pub fn finish(&self) -> Result<(), ScryfallClientError> {
+        if self.closed {
+            Ok(())
+        } else {
+            Err(ScryfallClientError::Deserialize {
+                path: JsonPath::default(),
+                snippet: String::new(),
+                source: Arc::new(de::Error::custom("Unexpected end of JSON array")),
+                attempts: None,
+            })
+        }
+    }
+}
+
+/// Lazily decodes a byte stream containing a JSON array into its elements.
+pub fn decode_json_array<T, S, B, E>(bytes: S) -> impl Stream<Item = Result<T, ScryfallClientError>>
+where
+    T: DeserializeOwned,
+    S: Stream<Item = Result<B, E>>,
+    B: AsRef<[u8]>,
+    E: Into<ScryfallClientError>,
+{
+    stream::try_unfold(
+        (Box::pin(bytes), JsonArrayDecoder::new()),
+        |(mut bytes, mut decoder)| async move {
+            loop {
+                if let Some(element) = decoder.next_element() {
+                    return element.map(|element| Some((element, (bytes, decoder))));
+                }
+                match bytes.try_next().await.map_err(Into::into)? {
+                    Some(chunk) => decoder.push(chunk.as_ref()),
+                    None => {
+                        decoder.finish()?;
+                        return Ok(None);
+                    }
+                }
+            }
+        },
+    )
+}
+
+fn malformed(byte: u8) -> ScryfallClientError {
+    ScryfallClientError::Deserialize {
+        path: JsonPath::default(),
+        snippet: (byte as char).to_string(),
+        source: Arc::new(de::Error::custom(format!(
+            "Unexpected byte {:?} in JSON array",
+            byte as char
+        ))),
+        attempts: None,
+    }
+}
+
+fn decode_element<T: DeserializeOwned>(element: &[u8]) -> Result<T, ScryfallClientError> {
+    let mut deserializer = serde_json::Deserializer::from_slice(element);
+    serde_path_to_error::deserialize(&mut deserializer)
+        .map_err(|err| ScryfallClientError::deserialize(element, err.into()))
+}
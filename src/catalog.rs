@@ -0,0 +1,66 @@
+use serde_derive::{Deserialize, Serialize};
+use url::Url;
+
+/// A flat list of strings, such as card names or creature types.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Catalog {
+    /// always "catalog"
+    pub object: String,
+    /// link to this catalog on scryfall's API, absent for autocomplete results
+    pub uri: Option<Url>,
+    pub total_values: usize,
+    pub data: Vec<String>,
+}
+
+/// Every catalog served under `/catalog/*`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CatalogKind {
+    CardNames,
+    ArtistNames,
+    WordBank,
+    Supertypes,
+    CardTypes,
+    ArtifactTypes,
+    BattleTypes,
+    CreatureTypes,
+    EnchantmentTypes,
+    LandTypes,
+    PlaneswalkerTypes,
+    SpellTypes,
+    Powers,
+    Toughnesses,
+    Loyalties,
+    FlavorWords,
+    KeywordAbilities,
+    KeywordActions,
+    AbilityWords,
+    Watermarks,
+}
+
+impl CatalogKind {
+    /// The path segment of this catalog, e.g. `"creature-types"`.
+    pub fn path(&self) -> &'static str {
+        match self {
+            CatalogKind::CardNames => "card-names",
+            CatalogKind::ArtistNames => "artist-names",
+            CatalogKind::WordBank => "word-bank",
+            CatalogKind::Supertypes => "supertypes",
+            CatalogKind::CardTypes => "card-types",
+            CatalogKind::ArtifactTypes => "artifact-types",
+            CatalogKind::BattleTypes => "battle-types",
+            CatalogKind::CreatureTypes => "creature-types",
+            CatalogKind::EnchantmentTypes => "enchantment-types",
+            CatalogKind::LandTypes => "land-types",
+            CatalogKind::PlaneswalkerTypes => "planeswalker-types",
+            CatalogKind::SpellTypes => "spell-types",
+            CatalogKind::Powers => "powers",
+            CatalogKind::Toughnesses => "toughnesses",
+            CatalogKind::Loyalties => "loyalties",
+            CatalogKind::FlavorWords => "flavor-words",
+            CatalogKind::KeywordAbilities => "keyword-abilities",
+            CatalogKind::KeywordActions => "keyword-actions",
+            CatalogKind::AbilityWords => "ability-words",
+            CatalogKind::Watermarks => "watermarks",
+        }
+    }
+}
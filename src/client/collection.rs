@@ -0,0 +1,55 @@
+use crate::structs::{Card, OracleID, ScryfallID};
+use serde_derive::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// One way of identifying a card in a `/cards/collection` request.
+///
+/// Scryfall echoes unmatched identifiers back verbatim, so this type is also
+/// used for [`Collection::not_found`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CardIdentifier {
+    Id {
+        id: ScryfallID,
+    },
+    MtgoId {
+        mtgo_id: i32,
+    },
+    MultiverseId {
+        multiverse_id: i32,
+    },
+    OracleId {
+        oracle_id: OracleID,
+    },
+    IllustrationId {
+        illustration_id: Uuid,
+    },
+    // must come before `Name` so untagged deserialization keeps the set
+    NameSet {
+        name: String,
+        set: String,
+    },
+    Name {
+        name: String,
+    },
+    SetCollectorNumber {
+        set: String,
+        collector_number: String,
+    },
+}
+
+impl CardIdentifier {
+    /// Maximum number of identifiers Scryfall accepts in a single request.
+    pub const MAX_PER_REQUEST: usize = 75;
+}
+
+/// Result of resolving a list of [`CardIdentifier`]s.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Collection {
+    /// Every card that was found, in the order of the input identifiers.
+    pub cards: Vec<Card>,
+    /// Identifiers that did not match any card.
+    pub not_found: Vec<CardIdentifier>,
+    /// Non-fatal warnings about the request, gathered from every batch.
+    pub warnings: Vec<String>,
+}
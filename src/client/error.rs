@@ -0,0 +1,348 @@
+use super::network_types::ScryfallError;
+use serde_json::Value;
+use std::error::Error as StdError;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Longest snippet of an offending response kept in an error.
+const SNIPPET_LEN: usize = 200;
+
+/// Everything that can go wrong when talking to Scryfall.
+///
+/// Only [`ScryfallClientError::Api`] and [`ScryfallClientError::RateLimited`]
+/// carry an error object sent by Scryfall; the other variants describe
+/// failures on the way there or back, so a DNS failure can be told apart
+/// from a real Scryfall 500 or a response that doesn't match this crate's
+/// types.
+#[derive(Debug, Clone, Error)]
+pub enum ScryfallClientError {
+    /// The request could not be sent or its response could not be read,
+    /// e.g. a DNS, connection, TLS or I/O failure.
+    #[error("Transport error: {message}")]
+    Transport {
+        message: String,
+        #[source]
+        source: Option<Arc<dyn StdError + Send + Sync>>,
+        /// requests sent before giving up, see [`ScryfallClientError::attempts`]
+        attempts: Option<u32>,
+    },
+    /// The request did not complete in time.
+    #[error("Request timed out: {message}")]
+    Timeout {
+        message: String,
+        #[source]
+        source: Option<Arc<dyn StdError + Send + Sync>>,
+        /// requests sent before giving up, see [`ScryfallClientError::attempts`]
+        attempts: Option<u32>,
+    },
+    /// Scryfall answered 429 Too Many Requests, and retrying didn't help.
+    #[error("Rate limited by Scryfall: {}", .error.details)]
+    RateLimited {
+        /// delay requested by the `Retry-After` header
+        retry_after: Option<Duration>,
+        #[source]
+        error: Box<ScryfallError>,
+    },
+    /// Scryfall answered with an error object, e.g. a 404 for an unknown card.
+    #[error("Scryfall API error {}: {}", .0.status, .0.details)]
+    Api(#[source] Box<ScryfallError>),
+    /// The server answered with an unsuccessful status and no Scryfall error
+    /// object, e.g. a CDN error page.
+    #[error("HTTP error {status}: {snippet}")]
+    Http {
+        status: u16,
+        snippet: String,
+        /// requests sent before giving up, see [`ScryfallClientError::attempts`]
+        attempts: Option<u32>,
+    },
+    /// The response was not the JSON this crate expected.
+    #[error("Failed to decode response at `{path}`: {source}")]
+    Deserialize {
+        /// where in the document decoding failed, e.g. `data[3].legalities`
+        path: JsonPath,
+        /// the offending part of the response
+        snippet: String,
+        #[source]
+        source: Arc<serde_json::Error>,
+        /// requests sent before giving up, see [`ScryfallClientError::attempts`]
+        attempts: Option<u32>,
+    },
+}
+
+impl ScryfallClientError {
+    /// The HTTP status of the response, if one was received.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            ScryfallClientError::Api(err) | ScryfallClientError::RateLimited { error: err, .. } => {
+                Some(err.status)
+            }
+            ScryfallClientError::Http { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// The error object sent by Scryfall, if any.
+    pub fn api_error(&self) -> Option<&ScryfallError> {
+        match self {
+            ScryfallClientError::Api(err) | ScryfallClientError::RateLimited { error: err, .. } => {
+                Some(err)
+            }
+            _ => None,
+        }
+    }
+
+    /// How many times the request was sent before giving up, set on errors
+    /// from GET requests, which the client retries.
+    ///
+    /// For [`ScryfallClientError::Api`] and
+    /// [`ScryfallClientError::RateLimited`] this is
+    /// [`ScryfallError::attempts`].
+    pub fn attempts(&self) -> Option<u32> {
+        match self {
+            ScryfallClientError::Api(err) | ScryfallClientError::RateLimited { error: err, .. } => {
+                err.attempts
+            }
+            ScryfallClientError::Transport { attempts, .. }
+            | ScryfallClientError::Timeout { attempts, .. }
+            | ScryfallClientError::Http { attempts, .. }
+            | ScryfallClientError::Deserialize { attempts, .. } => *attempts,
+        }
+    }
+
+    /// Records how many requests were sent before this error was returned.
+    pub(crate) fn with_attempts(mut self, count: u32) -> Self {
+        match &mut self {
+            ScryfallClientError::Api(err) | ScryfallClientError::RateLimited { error: err, .. } => {
+                err.attempts = Some(count);
+            }
+            ScryfallClientError::Transport { attempts, .. }
+            | ScryfallClientError::Timeout { attempts, .. }
+            | ScryfallClientError::Http { attempts, .. }
+            | ScryfallClientError::Deserialize { attempts, .. } => *attempts = Some(count),
+        }
+        self
+    }
+
+    pub(crate) fn transport(
+        message: impl Into<String>,
+        source: impl StdError + Send + Sync + 'static,
+    ) -> Self {
+        ScryfallClientError::Transport {
+            message: message.into(),
+            source: Some(Arc::new(source)),
+            attempts: None,
+        }
+    }
+
+    /// A decoding error for `body`, which failed as described by `err`.
+    pub(crate) fn deserialize(body: &[u8], err: DecodeError) -> Self {
+        let DecodeError { path, source } = err;
+        // only parse the body again to point at the offending part; syntax
+        // errors have nothing to point at, so show the text around them
+        let snippet = match serde_json::from_slice::<Value>(body) {
+            Ok(value) => truncate(locate(&value, &path).unwrap_or(&value).to_string()),
+            Err(_) => snippet_at(body, &source),
+        };
+        ScryfallClientError::Deserialize {
+            path,
+            snippet,
+            source: Arc::new(source),
+            attempts: None,
+        }
+    }
+
+    /// An unsuccessful response whose body isn't a Scryfall error object.
+    pub(crate) fn http(status: u16, body: &[u8]) -> Self {
+        ScryfallClientError::Http {
+            status,
+            snippet: truncate(String::from_utf8_lossy(body).into_owned()),
+            attempts: None,
+        }
+    }
+}
+
+fn locate<'a>(value: &'a Value, path: &JsonPath) -> Option<&'a Value> {
+    path.0
+        .iter()
+        .try_fold(value, |value, segment| match segment {
+            PathSegment::Field(key) => value.get(key),
+            PathSegment::Index(index) => value.get(index),
+        })
+}
+
+/// The text of `body` around the line and column reported by `source`.
+fn snippet_at(body: &[u8], source: &serde_json::Error) -> String {
+    // serde_json counts lines and columns from 1; column 0 means the
+    // error is about the end of input
+    let line_start: usize = body
+        .split(|&byte| byte == b'\n')
+        .take(source.line().saturating_sub(1))
+        .map(|line| line.len() + 1)
+        .sum();
+    let offset = (line_start + source.column().saturating_sub(1)).min(body.len());
+    let start = offset.saturating_sub(SNIPPET_LEN / 2);
+    let end = (offset + SNIPPET_LEN / 2).min(body.len());
+    String::from_utf8_lossy(&body[start..end]).into_owned()
+}
+
+fn truncate(mut snippet: String) -> String {
+    if snippet.len() > SNIPPET_LEN {
+        let mut end = SNIPPET_LEN;
+        while !snippet.is_char_boundary(end) {
+            end -= 1;
+        }
+        snippet.truncate(end);
+        snippet.push('…');
+    }
+    snippet
+}
+
+/// Location of a value inside a JSON document, e.g. `data[3].legalities`.
+///
+/// The document root is displayed as `.`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct JsonPath(pub Vec<PathSegment>);
+
+/// One step of a [`JsonPath`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PathSegment {
+    /// a field of an object
+    Field(String),
+    /// an element of an array
+    Index(usize),
+}
+
+impl fmt::Display for JsonPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return f.write_str(".");
+        }
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                PathSegment::Field(key) if i == 0 => write!(f, "{key}")?,
+                PathSegment::Field(key) => write!(f, ".{key}")?,
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<&serde_path_to_error::Path> for JsonPath {
+    fn from(path: &serde_path_to_error::Path) -> Self {
+        use serde_path_to_error::Segment;
+
+        JsonPath(
+            path.iter()
+                .map(|segment| match segment {
+                    Segment::Seq { index } => PathSegment::Index(*index),
+                    Segment::Map { key } => PathSegment::Field(key.clone()),
+                    Segment::Enum { variant } => PathSegment::Field(variant.clone()),
+                    Segment::Unknown => PathSegment::Field("?".to_string()),
+                })
+                .collect(),
+        )
+    }
+}
+
+/// A JSON document that didn't match the type it was decoded as.
+#[derive(Debug, Error)]
+#[error("{path}: {source}")]
+pub struct DecodeError {
+    /// where in the document decoding failed
+    pub path: JsonPath,
+    #[source]
+    pub source: serde_json::Error,
+}
+
+impl DecodeError {
+    /// Moves the error's location below `prefix`, for a document that was
+    /// decoded on its own but sits inside a larger one.
+    pub(crate) fn within(mut self, prefix: impl IntoIterator<Item = PathSegment>) -> Self {
+        let mut path: Vec<_> = prefix.into_iter().collect();
+        path.append(&mut self.path.0);
+        self.path = JsonPath(path);
+        self
+    }
+}
+
+impl From<serde_path_to_error::Error<serde_json::Error>> for DecodeError {
+    fn from(err: serde_path_to_error::Error<serde_json::Error>) -> Self {
+        DecodeError {
+            path: err.path().into(),
+            source: err.into_inner(),
+        }
+    }
+}
+
+impl From<serde_json::Error> for DecodeError {
+    fn from(source: serde_json::Error) -> Self {
+        DecodeError {
+            path: JsonPath::default(),
+            source,
+        }
+    }
+}
+
+impl From<ScryfallError> for ScryfallClientError {
+    fn from(err: ScryfallError) -> Self {
+        ScryfallClientError::Api(Box::new(err))
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl From<reqwest::Error> for ScryfallClientError {
+    fn from(err: reqwest::Error) -> Self {
+        let message = err.to_string();
+        if err.is_timeout() {
+            ScryfallClientError::Timeout {
+                message,
+                source: Some(Arc::new(err)),
+                attempts: None,
+            }
+        } else {
+            ScryfallClientError::transport(message, err)
+        }
+    }
+}
+
+#[cfg(not(feature = "tokio"))]
+impl From<ureq::Error> for ScryfallClientError {
+    fn from(err: ureq::Error) -> Self {
+        let mut timed_out = false;
+        let mut source = err.source();
+        while let Some(cause) = source {
+            timed_out |= cause
+                .downcast_ref::<std::io::Error>()
+                .is_some_and(|io| io.kind() == std::io::ErrorKind::TimedOut);
+            source = cause.source();
+        }
+        let message = err.to_string();
+        if timed_out {
+            ScryfallClientError::Timeout {
+                message,
+                source: Some(Arc::new(err)),
+                attempts: None,
+            }
+        } else {
+            ScryfallClientError::transport(message, err)
+        }
+    }
+}
+
+impl From<std::io::Error> for ScryfallClientError {
+    fn from(err: std::io::Error) -> Self {
+        let message = err.to_string();
+        if err.kind() == std::io::ErrorKind::TimedOut {
+            ScryfallClientError::Timeout {
+                message,
+                source: Some(Arc::new(err)),
+                attempts: None,
+            }
+        } else {
+            ScryfallClientError::transport(message, err)
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use super::error::ScryfallClientError;
+use futures::stream::BoxStream;
+use std::time::Duration;
+use url::Url;
+
+#[cfg(not(any(feature = "tokio", feature = "smol")))]
+compile_error!("scryfall-rs needs one of the `tokio`, `async-std` or `smol` features");
+
+// `tokio` is a default feature, so `features = ["smol"]` without
+// `default-features = false` would quietly pick reqwest and tokio timers
+#[cfg(all(feature = "tokio", feature = "smol"))]
+compile_error!(
+    "scryfall-rs's `tokio` and `smol`/`async-std` features are mutually exclusive; \
+     disable default features to use `smol` or `async-std`"
+);
+
+/// The body of a download, delivered in chunks.
+pub type ByteStream = BoxStream<'static, Result<Vec<u8>, ScryfallClientError>>;
+
+/// Status code and raw body of an HTTP response.
+#[derive(Debug)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: Vec<u8>,
+    /// Delay requested by a `Retry-After` header, if any.
+    pub retry_after: Option<Duration>,
+}
+
+/// The HTTP client backing [`super::scryfall_client::ScryfallClient`].
+///
+/// With the `tokio` feature this wraps `reqwest`; with `async-std` or `smol`
+/// it runs `ureq` on a background thread pool so no executor thread is ever
+/// blocked.
+///
+/// The backends are mutually exclusive. `tokio` is a default feature, and
+/// reqwest needs a tokio reactor, so `smol` and `async-std` only build with
+/// `default-features = false`.
+#[derive(Clone)]
+pub struct HttpClient {
+    #[cfg(feature = "tokio")]
+    inner: reqwest::Client,
+    #[cfg(not(feature = "tokio"))]
+    inner: ureq::Agent,
+}
+
+#[cfg(feature = "tokio")]
+impl HttpClient {
+    pub fn new(user_agent: &str) -> Self {
+        let inner = reqwest::ClientBuilder::new()
+            .user_agent(user_agent)
+            .build()
+            .unwrap();
+        HttpClient { inner }
+    }
+
+    pub async fn get(&self, url: &Url) -> Result<HttpResponse, ScryfallClientError> {
+        let response = self.inner.get(url.clone()).send().await;
+        Self::read(response).await
+    }
+
+    pub async fn post_json(
+        &self,
+        url: &Url,
+        body: Vec<u8>,
+    ) -> Result<HttpResponse, ScryfallClientError> {
+        let response = self
+            .inner
+            .post(url.clone())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body)
+            .send()
+            .await;
+        Self::read(response).await
+    }
+
+    async fn read(
+        response: Result<reqwest::Response, reqwest::Error>,
+    ) -> Result<HttpResponse, ScryfallClientError> {
+        let response = response.map_err(ScryfallClientError::from)?;
+        let status = response.status().as_u16();
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_retry_after);
+        let body = response.bytes().await.map_err(ScryfallClientError::from)?;
+        Ok(HttpResponse {
+            status,
+            body: body.to_vec(),
+            retry_after,
+        })
+    }
+
+    /// Starts a download and returns its body as a stream of chunks.
+    pub async fn get_stream(&self, url: &Url) -> Result<ByteStream, ScryfallClientError> {
+        use futures::TryStreamExt;
+
+        let response = self
+            .inner
+            .get(url.clone())
+            .send()
+            .await
+            .map_err(ScryfallClientError::from)?;
+        check_download_status(response.status().as_u16(), url)?;
+        Ok(Box::pin(
+            response
+                .bytes_stream()
+                .map_ok(|chunk| chunk.to_vec())
+                .map_err(ScryfallClientError::from),
+        ))
+    }
+}
+
+#[cfg(not(feature = "tokio"))]
+impl HttpClient {
+    pub fn new(user_agent: &str) -> Self {
+        let inner = ureq::AgentBuilder::new().user_agent(user_agent).build();
+        HttpClient { inner }
+    }
+
+    pub async fn get(&self, url: &Url) -> Result<HttpResponse, ScryfallClientError> {
+        let request = self.inner.request_url("GET", url);
+        blocking::unblock(move || Self::read(request.call())).await
+    }
+
+    pub async fn post_json(
+        &self,
+        url: &Url,
+        body: Vec<u8>,
+    ) -> Result<HttpResponse, ScryfallClientError> {
+        let request = self
+            .inner
+            .request_url("POST", url)
+            .set("Content-Type", "application/json");
+        blocking::unblock(move || Self::read(request.send_bytes(&body))).await
+    }
+
+    fn response(
+        response: Result<ureq::Response, ureq::Error>,
+    ) -> Result<ureq::Response, ScryfallClientError> {
+        // Scryfall describes API errors in the body, so keep reading on 4xx/5xx
+        match response {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => Ok(response),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn read(
+        response: Result<ureq::Response, ureq::Error>,
+    ) -> Result<HttpResponse, ScryfallClientError> {
+        use std::io::Read;
+
+        let response = Self::response(response)?;
+        let status = response.status();
+        let retry_after = response.header("Retry-After").and_then(parse_retry_after);
+        let mut body = Vec::new();
+        response
+            .into_reader()
+            .read_to_end(&mut body)
+            .map_err(ScryfallClientError::from)?;
+        Ok(HttpResponse {
+            status,
+            body,
+            retry_after,
+        })
+    }
+
+    /// Starts a download and returns its body as a stream of chunks.
+    pub async fn get_stream(&self, url: &Url) -> Result<ByteStream, ScryfallClientError> {
+        use futures::AsyncReadExt;
+        use futures::stream;
+
+        let request = self.inner.request_url("GET", url);
+        let response = blocking::unblock(move || Self::response(request.call())).await?;
+        check_download_status(response.status(), url)?;
+        let reader = blocking::Unblock::new(response.into_reader());
+        Ok(Box::pin(stream::try_unfold(
+            reader,
+            |mut reader| async move {
+                let mut chunk = vec![0; 64 * 1024];
+                let read = reader
+                    .read(&mut chunk)
+                    .await
+                    .map_err(ScryfallClientError::from)?;
+                if read == 0 {
+                    return Ok(None);
+                }
+                chunk.truncate(read);
+                Ok(Some((chunk, reader)))
+            },
+        )))
+    }
+}
+
+/// Reads a `Retry-After` header; only the delay-seconds form is understood.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    value.trim().parse().ok().map(Duration::from_secs)
+}
+
+pub(crate) fn check_download_status(status: u16, url: &Url) -> Result<(), ScryfallClientError> {
+    if (200..300).contains(&status) {
+        Ok(())
+    } else {
+        let details = format!("Failed to download {url}");
+        Err(ScryfallClientError::http(status, details.as_bytes()))
+    }
+}
+
+/// Waits without blocking the executor thread.
+#[cfg(feature = "tokio")]
+pub async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+/// Waits without blocking the executor thread.
+#[cfg(not(feature = "tokio"))]
+pub async fn sleep(duration: Duration) {
+    async_io::Timer::after(duration).await;
+}
@@ -1,2 +1,10 @@
+pub mod collection;
+pub mod error;
+pub mod http;
 pub mod network_types;
+pub mod rate_limit;
+pub mod retry;
 pub mod scryfall_client;
+pub mod search;
+pub mod transport;
+pub mod vcr;
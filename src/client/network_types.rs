@@ -1,23 +1,320 @@
+use super::collection::CardIdentifier;
+use super::error::{DecodeError, JsonPath, PathSegment, ScryfallClientError};
+use crate::bulk_data::BulkData;
+use crate::catalog::Catalog;
+use crate::ruling::Ruling;
+use crate::set::Set;
 use crate::structs::Card;
-use serde::Deserialize;
+use crate::symbology::{CardSymbol, ManaCost};
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
+use std::sync::Arc;
 use thiserror::Error;
+use url::Url;
 
-#[derive(Deserialize, Debug)]
-#[serde(untagged)]
+/// Any object returned by the Scryfall API, decoded according to its
+/// `object` field.
+#[derive(Debug)]
 pub enum ScryfallResponse {
-    #[serde(rename = "card")]
     Card(Box<Card>),
-    #[serde(rename = "error")]
+    List(List<ScryfallResponse>),
+    Catalog(Catalog),
+    Set(Box<Set>),
+    Ruling(Ruling),
+    CardSymbol(CardSymbol),
+    ManaCost(ManaCost),
+    BulkData(Box<BulkData>),
     Error(ScryfallError),
 }
 
-#[derive(Deserialize, Debug, Error)]
+impl ScryfallResponse {
+    /// The Scryfall `object` name of this response.
+    pub fn object(&self) -> &'static str {
+        match self {
+            ScryfallResponse::Card(_) => "card",
+            ScryfallResponse::List(_) => "list",
+            ScryfallResponse::Catalog(_) => "catalog",
+            ScryfallResponse::Set(_) => "set",
+            ScryfallResponse::Ruling(_) => "ruling",
+            ScryfallResponse::CardSymbol(_) => "card_symbol",
+            ScryfallResponse::ManaCost(_) => "mana_cost",
+            ScryfallResponse::BulkData(_) => "bulk_data",
+            ScryfallResponse::Error(_) => "error",
+        }
+    }
+
+    fn unexpected(self, expected: &str) -> ScryfallClientError {
+        match self {
+            ScryfallResponse::Error(err) => err.into(),
+            other => ScryfallClientError::Deserialize {
+                path: JsonPath(vec![PathSegment::Field("object".to_string())]),
+                snippet: format!("{:?}", other.object()),
+                source: Arc::new(de::Error::invalid_value(
+                    de::Unexpected::Str(other.object()),
+                    &expected,
+                )),
+                attempts: None,
+            },
+        }
+    }
+
+    /// Decodes `body` according to its `object` field.
+    ///
+    /// The payload is decoded straight from `body`, and each element of a
+    /// list straight from its own slice of `body`, so no intermediate
+    /// `serde_json::Value` is built. Errors report where in the document
+    /// decoding failed, e.g. `data[3].legalities`.
+    pub fn from_slice(body: &[u8]) -> Result<Self, DecodeError> {
+        // Scryfall objects carry their tag in an `object` field that the
+        // payload types also keep, so serde's internal tagging can't be used;
+        // a first pass reads the tag and skips over everything else
+        let Tagged { object } = decode(body)?;
+        Ok(match object {
+            Object::Card => ScryfallResponse::Card(decode(body)?),
+            Object::List => {
+                let list: List<&RawValue> = decode(body)?;
+                let data = list
+                    .data
+                    .iter()
+                    .enumerate()
+                    .map(|(index, element)| {
+                        ScryfallResponse::from_slice(element.get().as_bytes()).map_err(|err| {
+                            err.within([
+                                PathSegment::Field("data".to_string()),
+                                PathSegment::Index(index),
+                            ])
+                        })
+                    })
+                    .collect::<Result<_, _>>()?;
+                ScryfallResponse::List(List {
+                    data,
+                    has_more: list.has_more,
+                    next_page: list.next_page,
+                    total_cards: list.total_cards,
+                    warnings: list.warnings,
+                    not_found: list.not_found,
+                })
+            }
+            Object::Catalog => ScryfallResponse::Catalog(decode(body)?),
+            Object::Set => ScryfallResponse::Set(decode(body)?),
+            Object::Ruling => ScryfallResponse::Ruling(decode(body)?),
+            Object::CardSymbol => ScryfallResponse::CardSymbol(decode(body)?),
+            Object::ManaCost => ScryfallResponse::ManaCost(decode(body)?),
+            Object::BulkData => ScryfallResponse::BulkData(decode(body)?),
+            Object::Error => ScryfallResponse::Error(decode(body)?),
+        })
+    }
+}
+
+/// Decodes through [`ScryfallResponse::from_slice`], which needs the raw
+/// bytes of the object, so this only works with `serde_json` deserializers.
+/// The error location ends up in the message; use `from_slice` directly to
+/// keep it as a [`JsonPath`].
+impl<'de> Deserialize<'de> for ScryfallResponse {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = <Box<RawValue>>::deserialize(deserializer)?;
+        ScryfallResponse::from_slice(raw.get().as_bytes()).map_err(de::Error::custom)
+    }
+}
+
+/// The `object` tag of every Scryfall response.
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Object {
+    Card,
+    List,
+    Catalog,
+    Set,
+    Ruling,
+    CardSymbol,
+    ManaCost,
+    BulkData,
+    Error,
+}
+
+#[derive(Deserialize)]
+struct Tagged {
+    object: Object,
+}
+
+fn decode<'a, T: Deserialize<'a>>(body: &'a [u8]) -> Result<T, DecodeError> {
+    let mut deserializer = serde_json::Deserializer::from_slice(body);
+    let value = serde_path_to_error::deserialize(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(value)
+}
+
+impl TryFrom<ScryfallResponse> for Card {
+    type Error = ScryfallClientError;
+
+    fn try_from(response: ScryfallResponse) -> Result<Self, Self::Error> {
+        match response {
+            ScryfallResponse::Card(card) => Ok(*card),
+            other => Err(other.unexpected("card")),
+        }
+    }
+}
+
+impl TryFrom<ScryfallResponse> for Catalog {
+    type Error = ScryfallClientError;
+
+    fn try_from(response: ScryfallResponse) -> Result<Self, Self::Error> {
+        match response {
+            ScryfallResponse::Catalog(catalog) => Ok(catalog),
+            other => Err(other.unexpected("catalog")),
+        }
+    }
+}
+
+impl TryFrom<ScryfallResponse> for Set {
+    type Error = ScryfallClientError;
+
+    fn try_from(response: ScryfallResponse) -> Result<Self, Self::Error> {
+        match response {
+            ScryfallResponse::Set(set) => Ok(*set),
+            other => Err(other.unexpected("set")),
+        }
+    }
+}
+
+impl TryFrom<ScryfallResponse> for Ruling {
+    type Error = ScryfallClientError;
+
+    fn try_from(response: ScryfallResponse) -> Result<Self, Self::Error> {
+        match response {
+            ScryfallResponse::Ruling(ruling) => Ok(ruling),
+            other => Err(other.unexpected("ruling")),
+        }
+    }
+}
+
+impl TryFrom<ScryfallResponse> for CardSymbol {
+    type Error = ScryfallClientError;
+
+    fn try_from(response: ScryfallResponse) -> Result<Self, Self::Error> {
+        match response {
+            ScryfallResponse::CardSymbol(symbol) => Ok(symbol),
+            other => Err(other.unexpected("card_symbol")),
+        }
+    }
+}
+
+impl TryFrom<ScryfallResponse> for ManaCost {
+    type Error = ScryfallClientError;
+
+    fn try_from(response: ScryfallResponse) -> Result<Self, Self::Error> {
+        match response {
+            ScryfallResponse::ManaCost(cost) => Ok(cost),
+            other => Err(other.unexpected("mana_cost")),
+        }
+    }
+}
+
+impl TryFrom<ScryfallResponse> for BulkData {
+    type Error = ScryfallClientError;
+
+    fn try_from(response: ScryfallResponse) -> Result<Self, Self::Error> {
+        match response {
+            ScryfallResponse::BulkData(bulk_data) => Ok(*bulk_data),
+            other => Err(other.unexpected("bulk_data")),
+        }
+    }
+}
+
+impl<T: TryFrom<ScryfallResponse, Error = ScryfallClientError>> TryFrom<ScryfallResponse>
+    for List<T>
+{
+    type Error = ScryfallClientError;
+
+    fn try_from(response: ScryfallResponse) -> Result<Self, Self::Error> {
+        match response {
+            ScryfallResponse::List(list) => Ok(List {
+                data: list
+                    .data
+                    .into_iter()
+                    .map(T::try_from)
+                    .collect::<Result<_, _>>()?,
+                has_more: list.has_more,
+                next_page: list.next_page,
+                total_cards: list.total_cards,
+                warnings: list.warnings,
+                not_found: list.not_found,
+            }),
+            other => Err(other.unexpected("list")),
+        }
+    }
+}
+
+/// Scryfall's `list` object: one page of a possibly paginated sequence.
+#[derive(Deserialize, Debug)]
+pub struct List<T> {
+    pub data: Vec<T>,
+    pub has_more: bool,
+    /// Full URL of the next page, present when `has_more` is true.
+    pub next_page: Option<Url>,
+    /// Total number of cards across all pages, only sent for card lists.
+    pub total_cards: Option<usize>,
+    pub warnings: Option<Vec<String>>,
+    /// Identifiers that matched no card, only sent by `/cards/collection`.
+    pub not_found: Option<Vec<CardIdentifier>>,
+}
+
+#[derive(Deserialize, Debug, Clone, Error)]
 pub struct ScryfallError {
     pub status: u16,
     pub code: String,
     pub details: String,
-    pub type_: Option<String>,
+    /// Extra context for `code`, e.g. [`ErrorKind::Ambiguous`] for a name
+    /// that matched several cards.
+    #[serde(rename = "type")]
+    pub type_: Option<ErrorKind>,
+    /// Non-fatal problems with the request that Scryfall reported alongside
+    /// the error.
     pub warnings: Option<Vec<String>>,
+    /// How many times the request was sent before giving up, set on errors
+    /// from GET requests, which the client retries.
+    #[serde(skip)]
+    pub attempts: Option<u32>,
+}
+
+/// The `type` of a [`ScryfallError`].
+///
+/// Scryfall may add new types; anything not listed here is kept as
+/// [`ErrorKind::Unknown`] instead of failing to deserialize.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum ErrorKind {
+    /// A name lookup matched more than one card.
+    Ambiguous,
+    Unknown(String),
+}
+
+impl ErrorKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ErrorKind::Ambiguous => "ambiguous",
+            ErrorKind::Unknown(kind) => kind,
+        }
+    }
+}
+
+impl From<String> for ErrorKind {
+    fn from(kind: String) -> Self {
+        match kind.as_str() {
+            "ambiguous" => ErrorKind::Ambiguous,
+            _ => ErrorKind::Unknown(kind),
+        }
+    }
+}
+
+impl From<ErrorKind> for String {
+    fn from(kind: ErrorKind) -> Self {
+        match kind {
+            ErrorKind::Unknown(kind) => kind,
+            kind => kind.as_str().to_string(),
+        }
+    }
 }
 
 impl std::fmt::Display for ScryfallError {
@@ -26,18 +323,33 @@ impl std::fmt::Display for ScryfallError {
     }
 }
 
-pub trait ToScryfallError {
-    fn to_scryfall_error(&self) -> ScryfallError;
+/// Error returned by name lookups, which can fail because a name is ambiguous.
+#[derive(Debug, Error)]
+pub enum CardNamedError {
+    /// The name matched more than one card; the caller should ask for a more
+    /// specific name.
+    #[error("Ambiguous card name: {}", .0.details)]
+    Ambiguous(ScryfallError),
+    #[error(transparent)]
+    Client(ScryfallClientError),
+}
+
+impl CardNamedError {
+    pub fn into_client_error(self) -> ScryfallClientError {
+        match self {
+            CardNamedError::Ambiguous(err) => err.into(),
+            CardNamedError::Client(err) => err,
+        }
+    }
 }
 
-impl ToScryfallError for reqwest::Error {
-    fn to_scryfall_error(&self) -> ScryfallError {
-        ScryfallError {
-            status: 500,
-            code: "reqwest_error".to_string(),
-            details: self.to_string(),
-            type_: None,
-            warnings: None,
+impl From<ScryfallClientError> for CardNamedError {
+    fn from(err: ScryfallClientError) -> Self {
+        match err {
+            ScryfallClientError::Api(err) if err.type_ == Some(ErrorKind::Ambiguous) => {
+                CardNamedError::Ambiguous(*err)
+            }
+            err => CardNamedError::Client(err),
         }
     }
 }
@@ -0,0 +1,117 @@
+use super::http;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use url::Url;
+
+/// Groups of endpoints that Scryfall rate limits separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EndpointClass {
+    /// `/cards/search`, including every result page
+    Search,
+    /// `/cards/named`
+    Named,
+    /// `/cards/random`
+    Random,
+    /// `/cards/collection`
+    Collection,
+    /// every other endpoint
+    General,
+}
+
+impl EndpointClass {
+    /// Classifies a request URL by its path.
+    pub fn of(url: &Url) -> Self {
+        let path = url.path().trim_end_matches('/');
+        if path.ends_with("/cards/search") {
+            EndpointClass::Search
+        } else if path.ends_with("/cards/named") {
+            EndpointClass::Named
+        } else if path.ends_with("/cards/random") {
+            EndpointClass::Random
+        } else if path.ends_with("/cards/collection") {
+            EndpointClass::Collection
+        } else {
+            EndpointClass::General
+        }
+    }
+}
+
+/// Spaces requests out so the client stays within Scryfall's rate limits.
+///
+/// Each [`EndpointClass`] has its own minimum spacing, and every request,
+/// whatever its class, also respects the [`EndpointClass::General`] spacing
+/// so the overall request rate stays in bounds.
+///
+/// Works through `&self` and is `Send + Sync`, so one limiter can be shared
+/// by every task using a client. Concurrent callers each reserve the next
+/// free slot and sleep asynchronously until it arrives, which keeps them
+/// apart without holding a lock across the wait.
+#[derive(Debug)]
+pub struct RateLimit {
+    limits: HashMap<EndpointClass, Duration>,
+    /// earliest instant the next request of each class may be sent
+    next: Mutex<HashMap<EndpointClass, Instant>>,
+}
+
+impl Default for RateLimit {
+    /// Scryfall's documented limits: 2 requests per second for the search,
+    /// named, random and collection endpoints, 50 ms apart for the rest.
+    fn default() -> Self {
+        RateLimit::new(Duration::from_millis(50)) //delay requested by Scryfall good citizenship
+            .with_limit(EndpointClass::Search, Duration::from_millis(500))
+            .with_limit(EndpointClass::Named, Duration::from_millis(500))
+            .with_limit(EndpointClass::Random, Duration::from_millis(500))
+            .with_limit(EndpointClass::Collection, Duration::from_millis(500))
+    }
+}
+
+impl RateLimit {
+    /// Creates a limiter that spaces every endpoint `limit` apart.
+    pub fn new(limit: Duration) -> Self {
+        RateLimit {
+            limits: HashMap::from([
+                (EndpointClass::Search, limit),
+                (EndpointClass::Named, limit),
+                (EndpointClass::Random, limit),
+                (EndpointClass::Collection, limit),
+                (EndpointClass::General, limit),
+            ]),
+            next: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Sets the minimum spacing between requests of one endpoint class.
+    pub fn with_limit(mut self, class: EndpointClass, limit: Duration) -> Self {
+        self.limits.insert(class, limit);
+        self
+    }
+
+    pub fn limit(&self, class: EndpointClass) -> Duration {
+        self.limits[&class]
+    }
+
+    /// Waits until the caller may send its next request to `class`.
+    pub async fn check(&self, class: EndpointClass) {
+        let slot = {
+            let mut next = self.next.lock().unwrap_or_else(|e| e.into_inner());
+            let mut classes = vec![EndpointClass::General];
+            if class != EndpointClass::General {
+                classes.push(class);
+            }
+            let now = Instant::now();
+            let slot = classes
+                .iter()
+                .filter_map(|class| next.get(class).copied())
+                .fold(now, Instant::max);
+            for class in classes {
+                next.insert(class, slot + self.limit(class));
+            }
+            slot
+        };
+        let wait = slot.saturating_duration_since(Instant::now());
+        if !wait.is_zero() {
+            http::sleep(wait).await;
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use std::time::Duration;
+
+/// How the client retries GET requests that fail with a 429 or 5xx status.
+///
+/// The delay before retry `n` is `base_delay * 2^(n - 1)`, capped at
+/// `max_delay`, with a random jitter of up to half the delay taken off so
+/// concurrent clients don't retry in lockstep. A `Retry-After` header sent
+/// by Scryfall takes precedence over the computed delay. If it asks for
+/// longer than `max_delay`, the client stops retrying and returns the
+/// [`super::error::ScryfallClientError::RateLimited`] error, leaving the
+/// wait to the caller.
+///
+/// Only GETs are retried; POSTs such as `/cards/collection` are sent once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// total number of requests, including the first; `1` disables retries
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 4,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that sends every request exactly once.
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Whether a response with `status` is worth retrying.
+    pub fn should_retry(status: u16) -> bool {
+        status == 429 || (500..600).contains(&status)
+    }
+
+    /// Whether the policy waits as long as a `Retry-After` header asks, i.e.
+    /// no longer than `max_delay`.
+    pub fn honors(&self, retry_after: Option<Duration>) -> bool {
+        retry_after.is_none_or(|wait| wait <= self.max_delay)
+    }
+
+    /// The delay before sending attempt `attempt + 1`, after `attempt`
+    /// requests have failed.
+    pub fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(retry_after) = retry_after {
+            return retry_after;
+        }
+        let backoff = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_delay);
+        backoff - backoff.mul_f64(fastrand::f64() / 2.0)
+    }
+}
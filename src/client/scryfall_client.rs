@@ -1,97 +1,578 @@
-use super::network_types::{ScryfallError, ScryfallResponse, ToScryfallError};
-use crate::structs::Card;
-use reqwest::ClientBuilder;
+use super::collection::{CardIdentifier, Collection};
+use super::error::ScryfallClientError;
+use super::http::{self, HttpClient, HttpResponse};
+use super::network_types::{CardNamedError, List, ScryfallResponse};
+use super::rate_limit::{EndpointClass, RateLimit};
+use super::retry::RetryPolicy;
+use super::search::{SearchOptions, SearchResults};
+use super::transport::Transport;
+use crate::bulk_data::{BulkData, BulkDataType, decode_json_array};
+use crate::catalog::{Catalog, CatalogKind};
+use crate::ruling::Ruling;
+use crate::set::{Set, SetID};
+use crate::structs::{Card, Language, ScryfallID};
+use crate::symbology::{CardSymbol, ManaCost};
+use futures::stream::{self, Stream, TryStreamExt};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::sync::Arc;
+use url::Url;
 
 #[allow(dead_code)]
-async fn debug_scryfall_response(response: reqwest::Response) -> ScryfallResponse {
-    let json_str = response
-        .text()
-        .await
-        .map_err(|e| e.to_scryfall_error())
-        .unwrap();
+fn debug_scryfall_response(response: &HttpResponse) -> ScryfallResponse {
+    let json_str = String::from_utf8_lossy(&response.body);
     dbg!(&json_str);
     // parse the json string into ScryfallResponse
-    let json: ScryfallResponse = serde_json::from_str(&json_str)
-        .map_err(|e| ScryfallError {
-            status: 500,
-            code: "json_parse_error".to_string(),
-            details: e.to_string(),
-            type_: None,
-            warnings: None,
-        })
-        .unwrap();
+    let json: ScryfallResponse = serde_json::from_str(&json_str).unwrap();
     json
 }
 
-pub struct RateLimit {
-    pub limit: u128,
-    pub last: std::time::Instant,
+/// How `/cards/named` should match the requested name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NameMatch {
+    /// The name must match a card name exactly (case-insensitive).
+    Exact,
+    /// Scryfall picks the best match for a partial or misspelled name.
+    Fuzzy,
 }
 
-impl RateLimit {
-    fn new() -> Self {
-        RateLimit {
-            limit: 50, //delay requested by Scryfall good citizenship
-            last: std::time::Instant::now(),
-        }
-    }
-    fn check(&mut self) {
-        let now = std::time::Instant::now();
-        if now.duration_since(self.last).as_millis() > self.limit {
-            self.last = now;
-        }
-        let sleep = self.limit - now.duration_since(self.last).as_millis();
-        if sleep > 0 {
-            std::thread::sleep(std::time::Duration::from_millis(sleep as u64));
+impl NameMatch {
+    fn param(&self) -> &'static str {
+        match self {
+            NameMatch::Exact => "exact",
+            NameMatch::Fuzzy => "fuzzy",
         }
     }
 }
 
+/// The address of Scryfall's public API.
+pub const DEFAULT_BASE_URL: &str = "https://api.scryfall.com/";
+
 pub struct ScryfallClient {
-    pub client: reqwest::Client,
+    pub transport: Arc<dyn Transport>,
+    /// every endpoint path is resolved against this URL
+    pub base_url: Url,
     pub rate_limit: RateLimit,
+    pub retry: RetryPolicy,
 }
 
-impl ScryfallClient {
+/// Configures a [`ScryfallClient`], e.g. to point it at a local stand-in
+/// server or an in-process [`crate::FixtureTransport`].
+pub struct ScryfallClientBuilder {
+    user_agent: String,
+    base_url: Url,
+    transport: Option<Arc<dyn Transport>>,
+    rate_limit: RateLimit,
+    retry: RetryPolicy,
+}
+
+impl ScryfallClientBuilder {
     pub fn new(user_agent: &str) -> Self {
-        let client = ClientBuilder::new().user_agent(user_agent).build().unwrap();
+        ScryfallClientBuilder {
+            user_agent: user_agent.to_string(),
+            base_url: Url::parse(DEFAULT_BASE_URL).unwrap(),
+            transport: None,
+            rate_limit: RateLimit::default(),
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Sends requests to `base_url` instead of [`DEFAULT_BASE_URL`].
+    ///
+    /// A path prefix such as `http://localhost:8080/scryfall` is kept.
+    pub fn base_url(mut self, mut base_url: Url) -> Self {
+        // without the trailing slash, joining would replace the last segment
+        if !base_url.path().ends_with('/') {
+            let path = format!("{}/", base_url.path());
+            base_url.set_path(&path);
+        }
+        self.base_url = base_url;
+        self
+    }
+
+    /// Sends requests through `transport` instead of an [`HttpClient`].
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    pub fn rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limit = rate_limit;
+        self
+    }
+
+    pub fn retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    pub fn build(self) -> ScryfallClient {
+        let user_agent = self.user_agent;
         ScryfallClient {
-            client,
-            rate_limit: RateLimit::new(),
+            transport: self
+                .transport
+                .unwrap_or_else(|| Arc::new(HttpClient::new(&user_agent))),
+            base_url: self.base_url,
+            rate_limit: self.rate_limit,
+            retry: self.retry,
         }
     }
+}
 
-    pub async fn card_named(&mut self, name: &str) -> Result<Card, ScryfallError> {
-        let url = format!("https://api.scryfall.com/cards/named?exact={name}");
-        self.rate_limit.check();
-        let response = self
-            .client
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| e.to_scryfall_error())?;
+impl ScryfallClient {
+    pub fn new(user_agent: &str) -> Self {
+        Self::builder(user_agent).build()
+    }
+
+    pub fn builder(user_agent: &str) -> ScryfallClientBuilder {
+        ScryfallClientBuilder::new(user_agent)
+    }
+
+    /// Resolves an endpoint path such as `"cards/named"` against the base URL.
+    fn url(&self, path: &str) -> Url {
+        self.base_url
+            .join(path)
+            .expect("endpoint paths are always valid")
+    }
 
-        let json: ScryfallResponse = response.json().await.map_err(|e| e.to_scryfall_error())?;
-        match json {
-            ScryfallResponse::Card(card) => Ok(*card),
-            ScryfallResponse::Error(err) => Err(err),
+    /// Fetches `url` and decodes the response as `T`, turning Scryfall
+    /// error objects into `Err`.
+    ///
+    /// 429 and 5xx responses are retried according to `self.retry`, unless
+    /// `Retry-After` asks for a longer wait than it allows.
+    async fn fetch<T, Q>(&self, url: &str, query: &Q) -> Result<T, ScryfallClientError>
+    where
+        T: TryFrom<ScryfallResponse, Error = ScryfallClientError>,
+        Q: Serialize + ?Sized,
+    {
+        let url = with_query(url, query)?;
+        let mut attempt = 1;
+        loop {
+            self.rate_limit.check(EndpointClass::of(&url)).await;
+            let result = match self.transport.get(&url).await {
+                Ok(response)
+                    if RetryPolicy::should_retry(response.status)
+                        && attempt < self.retry.max_attempts
+                        && self.retry.honors(response.retry_after) =>
+                {
+                    http::sleep(self.retry.delay(attempt, response.retry_after)).await;
+                    attempt += 1;
+                    continue;
+                }
+                Ok(response) => decode(&response),
+                Err(err) => Err(err),
+            };
+            return result.map_err(|err| err.with_attempts(attempt));
         }
     }
 
-    pub async fn card_random(&mut self) -> Result<Card, ScryfallError> {
-        let url = "https://api.scryfall.com/cards/random";
-        self.rate_limit.check();
-        let response = self
-            .client
-            .get(url)
-            .send()
+    /// Posts `body` as JSON to `url` and decodes the response as `T`.
+    async fn post<T, B>(&self, url: &str, body: &B) -> Result<T, ScryfallClientError>
+    where
+        T: TryFrom<ScryfallResponse, Error = ScryfallClientError>,
+        B: Serialize + ?Sized,
+    {
+        let url = with_query(url, NO_QUERY)?;
+        let body = serde_json::to_vec(body).expect("request bodies always serialize");
+        self.rate_limit.check(EndpointClass::of(&url)).await;
+        let response = self.transport.post_json(&url, body).await?;
+        decode(&response)
+    }
+
+    pub async fn card_named(&self, name: &str) -> Result<Card, ScryfallClientError> {
+        self.card_named_with(name, NameMatch::Exact, None)
+            .await
+            .map_err(CardNamedError::into_client_error)
+    }
+
+    /// Looks a card up by a partial or misspelled name, e.g. "grimgrin corpse".
+    ///
+    /// `set` optionally restricts the lookup to a single set code.
+    pub async fn card_named_fuzzy(
+        &self,
+        name: &str,
+        set: Option<&str>,
+    ) -> Result<Card, CardNamedError> {
+        self.card_named_with(name, NameMatch::Fuzzy, set).await
+    }
+
+    /// Looks a card up by name using the given matching mode.
+    ///
+    /// If Scryfall reports that the name matches more than one card, the
+    /// error is returned as [`CardNamedError::Ambiguous`].
+    pub async fn card_named_with(
+        &self,
+        name: &str,
+        mode: NameMatch,
+        set: Option<&str>,
+    ) -> Result<Card, CardNamedError> {
+        let url = self.url("cards/named");
+        let mut query = vec![(mode.param(), name)];
+        if let Some(set) = set {
+            query.push(("set", set));
+        }
+        self.fetch(url.as_str(), &query)
             .await
-            .map_err(|e| e.to_scryfall_error())?;
+            .map_err(CardNamedError::from)
+    }
+
+    /// Fetches a random card, optionally restricted to cards matching a
+    /// search `query` such as `"is:commander"`.
+    pub async fn card_random(&self, query: Option<&str>) -> Result<Card, ScryfallClientError> {
+        let url = self.url("cards/random");
+        let query: Vec<_> = query.map(|q| ("q", q)).into_iter().collect();
+        self.fetch(url.as_str(), &query).await
+    }
+
+    /// Suggests up to 20 card names starting with or containing `partial`,
+    /// for type-ahead inputs.
+    ///
+    /// `include_extras` also suggests tokens, planes and other extra cards.
+    pub async fn autocomplete(
+        &self,
+        partial: &str,
+        include_extras: bool,
+    ) -> Result<Catalog, ScryfallClientError> {
+        let url = self.url("cards/autocomplete");
+        let mut query = vec![("q", partial)];
+        if include_extras {
+            query.push(("include_extras", "true"));
+        }
+        self.fetch(url.as_str(), &query).await
+    }
+
+    /// Fetches one of Scryfall's catalogs, e.g. every creature type.
+    pub async fn catalog(&self, kind: CatalogKind) -> Result<Catalog, ScryfallClientError> {
+        let url = self.url(&format!("catalog/{}", kind.path()));
+        self.fetch(url.as_str(), NO_QUERY).await
+    }
+
+    /// Fetches a single card by its Scryfall ID.
+    pub async fn card_by_id(&self, id: &ScryfallID) -> Result<Card, ScryfallClientError> {
+        let url = self.url(&format!("cards/{id}"));
+        self.fetch(url.as_str(), NO_QUERY).await
+    }
+
+    /// Fetches a specific printing by set code and collector number.
+    ///
+    /// When `lang` is given, the printing in that language is returned
+    /// instead of the English one.
+    pub async fn card_by_set_number(
+        &self,
+        set: &str,
+        collector_number: &str,
+        lang: Option<&Language>,
+    ) -> Result<Card, ScryfallClientError> {
+        let mut url = self.url("cards");
+        {
+            // push each segment separately so codes like "123★" get escaped
+            let mut segments = url.path_segments_mut().unwrap();
+            segments.push(set).push(collector_number);
+            if let Some(lang) = lang {
+                segments.push(lang.code());
+            }
+        }
+        self.fetch(url.as_str(), NO_QUERY).await
+    }
+
+    async fn card_by_platform_id(
+        &self,
+        platform: &str,
+        id: i32,
+    ) -> Result<Card, ScryfallClientError> {
+        let url = self.url(&format!("cards/{platform}/{id}"));
+        self.fetch(url.as_str(), NO_QUERY).await
+    }
+
+    /// Fetches the card with the given Gatherer multiverse ID.
+    pub async fn card_by_multiverse_id(&self, id: i32) -> Result<Card, ScryfallClientError> {
+        self.card_by_platform_id("multiverse", id).await
+    }
+
+    /// Fetches the card with the given Magic Online ID (also matches foil IDs).
+    pub async fn card_by_mtgo_id(&self, id: i32) -> Result<Card, ScryfallClientError> {
+        self.card_by_platform_id("mtgo", id).await
+    }
+
+    /// Fetches the card with the given MTG Arena ID.
+    pub async fn card_by_arena_id(&self, id: i32) -> Result<Card, ScryfallClientError> {
+        self.card_by_platform_id("arena", id).await
+    }
+
+    /// Fetches the card with the given TCGplayer product ID.
+    pub async fn card_by_tcgplayer_id(&self, id: i32) -> Result<Card, ScryfallClientError> {
+        self.card_by_platform_id("tcgplayer", id).await
+    }
+
+    /// Fetches the card with the given Cardmarket product ID.
+    pub async fn card_by_cardmarket_id(&self, id: i32) -> Result<Card, ScryfallClientError> {
+        self.card_by_platform_id("cardmarket", id).await
+    }
 
-        let json: ScryfallResponse = response.json().await.map_err(|e| e.to_scryfall_error())?;
-        match json {
-            ScryfallResponse::Card(card) => Ok(*card),
-            ScryfallResponse::Error(err) => Err(err),
+    /// Resolves many cards at once through `/cards/collection`.
+    ///
+    /// Identifiers are sent in batches of 75, Scryfall's per-request limit.
+    /// Found cards keep the order of `identifiers`; identifiers that matched
+    /// nothing are reported in [`Collection::not_found`].
+    pub async fn cards_collection(
+        &self,
+        identifiers: &[CardIdentifier],
+    ) -> Result<Collection, ScryfallClientError> {
+        let url = self.url("cards/collection");
+        let mut collection = Collection {
+            cards: Vec::with_capacity(identifiers.len()),
+            not_found: Vec::new(),
+            warnings: Vec::new(),
+        };
+        for chunk in identifiers.chunks(CardIdentifier::MAX_PER_REQUEST) {
+            let mut page: List<Card> = self
+                .post(url.as_str(), &CollectionRequest { identifiers: chunk })
+                .await?;
+            collection.cards.append(&mut page.data);
+            collection
+                .not_found
+                .append(&mut page.not_found.unwrap_or_default());
+            collection
+                .warnings
+                .append(&mut page.warnings.unwrap_or_default());
         }
+        Ok(collection)
+    }
+
+    pub(crate) async fn rulings_at(&self, url: &str) -> Result<Vec<Ruling>, ScryfallClientError> {
+        let list: List<Ruling> = self.fetch(url, NO_QUERY).await?;
+        Ok(list.data)
+    }
+
+    /// Fetches the rulings for the card with the given Scryfall ID.
+    pub async fn rulings_by_id(&self, id: &ScryfallID) -> Result<Vec<Ruling>, ScryfallClientError> {
+        let url = self.url(&format!("cards/{id}/rulings"));
+        self.rulings_at(url.as_str()).await
+    }
+
+    /// Fetches the rulings for the card with the given set code and
+    /// collector number.
+    pub async fn rulings_by_set_number(
+        &self,
+        set: &str,
+        collector_number: &str,
+    ) -> Result<Vec<Ruling>, ScryfallClientError> {
+        let mut url = self.url("cards");
+        url.path_segments_mut()
+            .unwrap()
+            .push(set)
+            .push(collector_number)
+            .push("rulings");
+        self.rulings_at(url.as_str()).await
+    }
+
+    /// Fetches the rulings for the card with the given Gatherer multiverse ID.
+    pub async fn rulings_by_multiverse_id(
+        &self,
+        id: i32,
+    ) -> Result<Vec<Ruling>, ScryfallClientError> {
+        let url = self.url(&format!("cards/multiverse/{id}/rulings"));
+        self.rulings_at(url.as_str()).await
+    }
+
+    /// Fetches the rulings for the card with the given Magic Online ID.
+    pub async fn rulings_by_mtgo_id(&self, id: i32) -> Result<Vec<Ruling>, ScryfallClientError> {
+        let url = self.url(&format!("cards/mtgo/{id}/rulings"));
+        self.rulings_at(url.as_str()).await
+    }
+
+    /// Fetches the rulings for the card with the given MTG Arena ID.
+    pub async fn rulings_by_arena_id(&self, id: i32) -> Result<Vec<Ruling>, ScryfallClientError> {
+        let url = self.url(&format!("cards/arena/{id}/rulings"));
+        self.rulings_at(url.as_str()).await
+    }
+
+    /// Fetches every set known to Scryfall.
+    pub async fn sets(&self) -> Result<Vec<Set>, ScryfallClientError> {
+        let url = self.url("sets");
+        let list: List<Set> = self.fetch(url.as_str(), NO_QUERY).await?;
+        Ok(list.data)
+    }
+
+    /// Fetches a set by its set code, e.g. `"lea"`, or its MTGO code.
+    pub async fn set_by_code(&self, code: &str) -> Result<Set, ScryfallClientError> {
+        let mut url = self.url("sets");
+        url.path_segments_mut().unwrap().push(code);
+        self.fetch(url.as_str(), NO_QUERY).await
+    }
+
+    /// Fetches a set by its Scryfall ID.
+    pub async fn set_by_id(&self, id: &SetID) -> Result<Set, ScryfallClientError> {
+        let url = self.url(&format!("sets/{id}"));
+        self.fetch(url.as_str(), NO_QUERY).await
+    }
+
+    /// Fetches the set with the given TCGplayer group ID.
+    pub async fn set_by_tcgplayer_id(&self, id: i32) -> Result<Set, ScryfallClientError> {
+        let url = self.url(&format!("sets/tcgplayer/{id}"));
+        self.fetch(url.as_str(), NO_QUERY).await
+    }
+
+    /// Fetches every card symbol Scryfall knows about.
+    pub async fn symbology(&self) -> Result<Vec<CardSymbol>, ScryfallClientError> {
+        let url = self.url("symbology");
+        let list: List<CardSymbol> = self.fetch(url.as_str(), NO_QUERY).await?;
+        Ok(list.data)
+    }
+
+    /// Parses a loosely written mana cost such as `"RUx"` into its
+    /// normalized form and color information.
+    pub async fn parse_mana(&self, cost: &str) -> Result<ManaCost, ScryfallClientError> {
+        let url = self.url("symbology/parse-mana");
+        self.fetch(url.as_str(), &[("cost", cost)]).await
     }
+
+    /// Lists every bulk data export currently available.
+    pub async fn bulk_data(&self) -> Result<Vec<BulkData>, ScryfallClientError> {
+        let url = self.url("bulk-data");
+        let list: List<BulkData> = self.fetch(url.as_str(), NO_QUERY).await?;
+        Ok(list.data)
+    }
+
+    /// Fetches the description of the latest bulk export of the given type.
+    pub async fn bulk_data_by_type(
+        &self,
+        type_: BulkDataType,
+    ) -> Result<BulkData, ScryfallClientError> {
+        let url = self.url(&format!("bulk-data/{}", type_.path()));
+        self.fetch(url.as_str(), NO_QUERY).await
+    }
+
+    /// Downloads a bulk export and yields its entries one at a time.
+    ///
+    /// The file is decompressed and decoded as it arrives, so it is never
+    /// held in memory as a whole. Use `T = Card` for card exports and
+    /// `T = Ruling` for [`BulkDataType::Rulings`].
+    pub fn bulk_data_download<T: DeserializeOwned>(
+        &self,
+        bulk_data: &BulkData,
+    ) -> impl Stream<Item = Result<T, ScryfallClientError>> + use<T> {
+        // bulk files are served from a CDN without rate limits
+        let transport = self.transport.clone();
+        let url = bulk_data.download_uri.clone();
+        stream::once(async move {
+            let bytes = transport.get_stream(&url).await?;
+            Ok::<_, ScryfallClientError>(decode_json_array(bytes))
+        })
+        .try_flatten()
+    }
+
+    /// Runs a full-text search and collects every page of results.
+    ///
+    /// `query` uses Scryfall's search syntax, e.g. `"t:goblin cmc<=2"`.
+    pub async fn search(
+        &self,
+        query: &str,
+        options: &SearchOptions,
+    ) -> Result<SearchResults, ScryfallClientError> {
+        let url = self.url("cards/search");
+        let mut page: List<Card> = self
+            .fetch(url.as_str(), &SearchQuery { q: query, options })
+            .await?;
+        let mut results = SearchResults {
+            cards: Vec::with_capacity(page.total_cards.unwrap_or_default()),
+            total_cards: page.total_cards.unwrap_or_default(),
+            warnings: page.warnings.take().unwrap_or_default(),
+        };
+        loop {
+            results.cards.append(&mut page.data);
+            match page.next_page.take() {
+                // next_page already carries every query parameter
+                Some(next) if page.has_more => {
+                    page = self.fetch(next.as_str(), NO_QUERY).await?;
+                }
+                _ => break,
+            }
+        }
+        Ok(results)
+    }
+
+    /// Like [`ScryfallClient::search`], but yields cards lazily.
+    ///
+    /// The next page is only requested once the consumer has taken every card
+    /// of the current one, and dropping the stream stops any further requests.
+    pub fn search_stream<'a>(
+        &'a self,
+        query: &str,
+        options: &SearchOptions,
+    ) -> impl Stream<Item = Result<Card, ScryfallClientError>> + use<'a> {
+        let mut url = self.url("cards/search");
+        let query = serde_urlencoded::to_string(SearchQuery { q: query, options })
+            .expect("search parameters are always url-encodable");
+        url.set_query(Some(&query));
+        self.card_list_stream(url)
+    }
+
+    /// Lazily walks every page of a paginated card list, such as
+    /// [`Card::prints_search_uri`] or [`Card::set_search_uri`].
+    pub fn card_list_stream(
+        &self,
+        url: Url,
+    ) -> impl Stream<Item = Result<Card, ScryfallClientError>> + '_ {
+        stream::try_unfold((self, Some(url)), |(client, next)| async move {
+            let Some(url) = next else {
+                return Ok::<_, ScryfallClientError>(None);
+            };
+            let page: List<Card> = client.fetch(url.as_str(), NO_QUERY).await?;
+            let next = if page.has_more { page.next_page } else { None };
+            let cards = stream::iter(page.data.into_iter().map(Ok));
+            Ok(Some((cards, (client, next))))
+        })
+        .try_flatten()
+    }
+}
+
+const NO_QUERY: &[(&str, &str)] = &[];
+
+fn with_query<Q: Serialize + ?Sized>(url: &str, query: &Q) -> Result<Url, ScryfallClientError> {
+    let mut url = Url::parse(url)
+        .map_err(|e| ScryfallClientError::transport(format!("Invalid URL {url:?}"), e))?;
+    let query = serde_urlencoded::to_string(query)
+        .map_err(|e| ScryfallClientError::transport("Invalid query parameters", e))?;
+    if !query.is_empty() {
+        url.set_query(Some(&query));
+    }
+    Ok(url)
+}
+
+/// Decodes a response body, telling Scryfall's error objects apart from
+/// bodies that aren't the expected JSON.
+fn decode<T>(response: &HttpResponse) -> Result<T, ScryfallClientError>
+where
+    T: TryFrom<ScryfallResponse, Error = ScryfallClientError>,
+{
+    let success = (200..300).contains(&response.status);
+    let json = match ScryfallResponse::from_slice(&response.body) {
+        Ok(json) => json,
+        Err(_) if !success => {
+            return Err(ScryfallClientError::http(response.status, &response.body));
+        }
+        Err(err) => return Err(ScryfallClientError::deserialize(&response.body, err)),
+    };
+    match T::try_from(json) {
+        Err(ScryfallClientError::Api(error)) if response.status == 429 => {
+            Err(ScryfallClientError::RateLimited {
+                retry_after: response.retry_after,
+                error,
+            })
+        }
+        result => result,
+    }
+}
+
+#[derive(Serialize)]
+struct CollectionRequest<'a> {
+    identifiers: &'a [CardIdentifier],
+}
+
+#[derive(Serialize)]
+struct SearchQuery<'a> {
+    q: &'a str,
+    #[serde(flatten)]
+    options: &'a SearchOptions,
 }
@@ -0,0 +1,98 @@
+use crate::structs::Card;
+use serde_derive::Serialize;
+
+/// Strategy for omitting similar cards from search results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum UniqueMode {
+    /// Remove duplicate gameplay objects (the default).
+    #[serde(rename = "cards")]
+    Cards,
+    /// Return each unique artwork once.
+    #[serde(rename = "art")]
+    Art,
+    /// Return every printing of each card.
+    #[serde(rename = "prints")]
+    Prints,
+}
+
+/// Field to sort search results by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum SortOrder {
+    #[serde(rename = "name")]
+    Name,
+    #[serde(rename = "set")]
+    Set,
+    #[serde(rename = "released")]
+    Released,
+    #[serde(rename = "rarity")]
+    Rarity,
+    #[serde(rename = "color")]
+    Color,
+    #[serde(rename = "usd")]
+    Usd,
+    #[serde(rename = "tix")]
+    Tix,
+    #[serde(rename = "eur")]
+    Eur,
+    #[serde(rename = "cmc")]
+    Cmc,
+    #[serde(rename = "power")]
+    Power,
+    #[serde(rename = "toughness")]
+    Toughness,
+    #[serde(rename = "edhrec")]
+    Edhrec,
+    #[serde(rename = "penny")]
+    Penny,
+    #[serde(rename = "artist")]
+    Artist,
+    #[serde(rename = "review")]
+    Review,
+    #[serde(rename = "spoiled")]
+    Spoiled,
+}
+
+/// Direction to sort search results in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum SortDirection {
+    /// Let Scryfall pick the natural direction for the sort order.
+    #[serde(rename = "auto")]
+    Auto,
+    #[serde(rename = "asc")]
+    Ascending,
+    #[serde(rename = "desc")]
+    Descending,
+}
+
+/// Optional parameters for `/cards/search`.
+///
+/// Fields left as `None`/`false` are omitted from the request so Scryfall's
+/// defaults apply.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize)]
+pub struct SearchOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unique: Option<UniqueMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<SortOrder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dir: Option<SortDirection>,
+    /// Include extra cards such as tokens and planes.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub include_extras: bool,
+    /// Include cards in every language.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub include_multilingual: bool,
+    /// Include rare card variants.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub include_variations: bool,
+}
+
+/// Every card matching a search, gathered across all result pages.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchResults {
+    pub cards: Vec<Card>,
+    /// Total number of matches as reported by Scryfall.
+    pub total_cards: usize,
+    /// Non-fatal warnings about the query, e.g. unrecognised parameters.
+    pub warnings: Vec<String>,
+}
@@ -0,0 +1,120 @@
+use super::error::ScryfallClientError;
+use super::http::{ByteStream, HttpClient, HttpResponse};
+use futures::future::BoxFuture;
+use futures::stream;
+use std::collections::HashMap;
+use url::Url;
+
+/// Sends the HTTP requests of a [`super::scryfall_client::ScryfallClient`].
+///
+/// [`HttpClient`] is the default implementation; swap in another one through
+/// [`super::scryfall_client::ScryfallClientBuilder::transport`] to run the
+/// client against fixtures or a recorded session instead of the network.
+pub trait Transport: Send + Sync {
+    fn get<'a>(&'a self, url: &'a Url) -> BoxFuture<'a, Result<HttpResponse, ScryfallClientError>>;
+
+    fn post_json<'a>(
+        &'a self,
+        url: &'a Url,
+        body: Vec<u8>,
+    ) -> BoxFuture<'a, Result<HttpResponse, ScryfallClientError>>;
+
+    /// Starts a download and returns its body as a stream of chunks.
+    ///
+    /// Defaults to a single chunk holding the whole [`Transport::get`] body.
+    fn get_stream<'a>(
+        &'a self,
+        url: &'a Url,
+    ) -> BoxFuture<'a, Result<ByteStream, ScryfallClientError>> {
+        Box::pin(async move {
+            let response = self.get(url).await?;
+            super::http::check_download_status(response.status, url)?;
+            let chunk: ByteStream = Box::pin(stream::once(async { Ok(response.body) }));
+            Ok(chunk)
+        })
+    }
+}
+
+impl Transport for HttpClient {
+    fn get<'a>(&'a self, url: &'a Url) -> BoxFuture<'a, Result<HttpResponse, ScryfallClientError>> {
+        Box::pin(HttpClient::get(self, url))
+    }
+
+    fn post_json<'a>(
+        &'a self,
+        url: &'a Url,
+        body: Vec<u8>,
+    ) -> BoxFuture<'a, Result<HttpResponse, ScryfallClientError>> {
+        Box::pin(HttpClient::post_json(self, url, body))
+    }
+
+    fn get_stream<'a>(
+        &'a self,
+        url: &'a Url,
+    ) -> BoxFuture<'a, Result<ByteStream, ScryfallClientError>> {
+        Box::pin(HttpClient::get_stream(self, url))
+    }
+}
+
+/// An in-process [`Transport`] that answers from canned responses.
+///
+/// Responses are keyed by path and query, e.g. `"/cards/named?exact=Black+Lotus"`,
+/// so they match whatever base URL the client was built with. POST bodies are
+/// ignored. Requests without a fixture get a Scryfall-style 404 error.
+#[derive(Debug, Clone, Default)]
+pub struct FixtureTransport {
+    responses: HashMap<String, (u16, Vec<u8>)>,
+}
+
+impl FixtureTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answers requests for `path_and_query` with `status` and `body`.
+    pub fn with_response(
+        mut self,
+        path_and_query: &str,
+        status: u16,
+        body: impl Into<Vec<u8>>,
+    ) -> Self {
+        self.responses
+            .insert(path_and_query.to_string(), (status, body.into()));
+        self
+    }
+
+    fn respond(&self, url: &Url) -> HttpResponse {
+        let key = match url.query() {
+            Some(query) => format!("{}?{query}", url.path()),
+            None => url.path().to_string(),
+        };
+        let (status, body) = self.responses.get(&key).cloned().unwrap_or_else(|| {
+            let error = serde_json::json!({
+                "object": "error",
+                "code": "not_found",
+                "status": 404,
+                "details": format!("No fixture for {key}"),
+            });
+            (404, error.to_string().into_bytes())
+        });
+        HttpResponse {
+            status,
+            body,
+            retry_after: None,
+        }
+    }
+}
+
+impl Transport for FixtureTransport {
+    fn get<'a>(&'a self, url: &'a Url) -> BoxFuture<'a, Result<HttpResponse, ScryfallClientError>> {
+        Box::pin(async move { Ok(self.respond(url)) })
+    }
+
+    fn post_json<'a>(
+        &'a self,
+        url: &'a Url,
+        _body: Vec<u8>,
+    ) -> BoxFuture<'a, Result<HttpResponse, ScryfallClientError>> {
+        Box::pin(async move { Ok(self.respond(url)) })
+    }
+}
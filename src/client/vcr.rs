@@ -0,0 +1,188 @@
+use super::error::ScryfallClientError;
+use super::http::HttpResponse;
+use super::transport::Transport;
+use futures::future::BoxFuture;
+use serde_derive::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use url::Url;
+
+/// A record/replay [`Transport`] for deterministic tests.
+///
+/// When recording, every request is forwarded to an inner transport and the
+/// URL, status and raw JSON response are saved as one file per request in a
+/// fixture directory. When replaying, responses are served from those files
+/// only, and any request that was never recorded fails with a
+/// [`ScryfallClientError::Transport`] error instead of reaching the network.
+///
+/// Recordings are matched on method, path, query and request body, so they
+/// replay regardless of the client's base URL.
+pub struct VcrTransport {
+    dir: PathBuf,
+    /// set while recording
+    inner: Option<Arc<dyn Transport>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Recording {
+    method: String,
+    url: Url,
+    status: u16,
+    /// the response, kept as JSON so fixtures stay readable
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<serde_json::Value>,
+    /// the response, for the rare body that isn't JSON
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+}
+
+impl VcrTransport {
+    /// Sends requests through `inner` and saves every response to `dir`.
+    pub fn record(dir: impl Into<PathBuf>, inner: impl Transport + 'static) -> Self {
+        VcrTransport {
+            dir: dir.into(),
+            inner: Some(Arc::new(inner)),
+        }
+    }
+
+    /// Serves responses recorded in `dir` without touching the network.
+    pub fn replay(dir: impl Into<PathBuf>) -> Self {
+        VcrTransport {
+            dir: dir.into(),
+            inner: None,
+        }
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.inner.is_some()
+    }
+
+    /// The fixture file a request is recorded to, e.g.
+    /// `get_cards_named_exact_Black_Lotus_1f0e….json`.
+    pub fn fixture_path(&self, method: &str, url: &Url, body: &[u8]) -> PathBuf {
+        let key = match url.query() {
+            Some(query) => format!("{}?{query}", url.path()),
+            None => url.path().to_string(),
+        };
+        // readable part of the name, built from the decoded path and query
+        let readable = url
+            .query_pairs()
+            .fold(url.path().to_string(), |name, (key, value)| {
+                format!("{name}_{key}_{value}")
+            });
+        let mut slug = String::new();
+        for c in readable.chars() {
+            if c.is_ascii_alphanumeric() {
+                slug.push(c);
+            } else if !slug.is_empty() && !slug.ends_with('_') {
+                slug.push('_');
+            }
+        }
+        slug.truncate(80);
+        let slug = slug.trim_end_matches('_');
+        // the slug is lossy, so a hash of the exact request keeps names unique
+        let hash = fnv1a(&[method.as_bytes(), key.as_bytes(), body].concat());
+        self.dir
+            .join(format!("{}_{slug}_{hash:016x}.json", method.to_lowercase()))
+    }
+
+    async fn request(
+        &self,
+        method: &str,
+        url: &Url,
+        body: Vec<u8>,
+    ) -> Result<HttpResponse, ScryfallClientError> {
+        let path = self.fixture_path(method, url, &body);
+        match &self.inner {
+            Some(inner) => {
+                let response = match method {
+                    "POST" => inner.post_json(url, body).await?,
+                    _ => inner.get(url).await?,
+                };
+                save(&path, method, url, &response)?;
+                Ok(response)
+            }
+            None => load(&path, method, url),
+        }
+    }
+}
+
+impl Transport for VcrTransport {
+    fn get<'a>(&'a self, url: &'a Url) -> BoxFuture<'a, Result<HttpResponse, ScryfallClientError>> {
+        Box::pin(self.request("GET", url, Vec::new()))
+    }
+
+    fn post_json<'a>(
+        &'a self,
+        url: &'a Url,
+        body: Vec<u8>,
+    ) -> BoxFuture<'a, Result<HttpResponse, ScryfallClientError>> {
+        Box::pin(self.request("POST", url, body))
+    }
+}
+
+fn save(
+    path: &Path,
+    method: &str,
+    url: &Url,
+    response: &HttpResponse,
+) -> Result<(), ScryfallClientError> {
+    let (body, text) = match serde_json::from_slice(&response.body) {
+        Ok(body) => (Some(body), None),
+        Err(_) => (
+            None,
+            Some(String::from_utf8_lossy(&response.body).into_owned()),
+        ),
+    };
+    let recording = Recording {
+        method: method.to_string(),
+        url: url.clone(),
+        status: response.status,
+        body,
+        text,
+    };
+    let mut json = serde_json::to_vec_pretty(&recording).expect("recordings always serialize");
+    json.push(b'\n');
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(ScryfallClientError::from)?;
+    }
+    std::fs::write(path, json).map_err(ScryfallClientError::from)
+}
+
+fn load(path: &Path, method: &str, url: &Url) -> Result<HttpResponse, ScryfallClientError> {
+    let json = match std::fs::read(path) {
+        Ok(json) => json,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Err(ScryfallClientError::Transport {
+                message: format!(
+                    "No recording of {method} {url}, expected {}",
+                    path.display()
+                ),
+                source: None,
+                attempts: None,
+            });
+        }
+        Err(err) => return Err(err.into()),
+    };
+    let recording: Recording = serde_json::from_slice(&json).map_err(|e| {
+        ScryfallClientError::transport(format!("Invalid recording {}", path.display()), e)
+    })?;
+    let body = match (recording.body, recording.text) {
+        (Some(body), _) => serde_json::to_vec(&body).expect("JSON values always serialize"),
+        (None, Some(text)) => text.into_bytes(),
+        (None, None) => Vec::new(),
+    };
+    Ok(HttpResponse {
+        status: recording.status,
+        body,
+        retry_after: None,
+    })
+}
+
+/// 64-bit FNV-1a, used because it is stable across Rust versions, unlike
+/// `DefaultHasher`.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
@@ -1,8 +1,31 @@
+pub mod bulk_data;
 pub mod card;
+pub mod catalog;
 pub mod client;
+pub mod local_db;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod ruling;
+pub mod set;
 pub mod structs;
+pub mod symbology;
 
+pub use bulk_data::*;
 pub use card::*;
+pub use catalog::*;
+pub use client::collection::*;
+pub use client::error::*;
 pub use client::network_types::*;
+pub use client::rate_limit::*;
+pub use client::retry::*;
 pub use client::scryfall_client::*;
+pub use client::search::*;
+pub use client::transport::*;
+pub use client::vcr::*;
+pub use local_db::*;
+#[cfg(feature = "mock")]
+pub use mock::*;
+pub use ruling::*;
+pub use set::*;
 pub use structs::*;
+pub use symbology::*;
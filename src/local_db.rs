@@ -0,0 +1,444 @@
+use crate::bulk_data::JsonArrayDecoder;
+use crate::client::error::ScryfallClientError;
+use crate::client::network_types::ScryfallError;
+use crate::client::scryfall_client::ScryfallClient;
+use crate::structs::{Card, Language, OracleID, ScryfallID};
+use futures::{Stream, TryStreamExt};
+use std::collections::HashMap;
+use std::future::{self, Future};
+use std::io::Read;
+
+/// Card lookups shared by [`ScryfallClient`] and [`LocalDb`], so code can be
+/// written once and run against either the live API or an offline copy.
+pub trait CardLookup {
+    fn card_named(
+        &self,
+        name: &str,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send;
+    fn card_by_id(
+        &self,
+        id: &ScryfallID,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send;
+    fn card_by_set_number(
+        &self,
+        set: &str,
+        collector_number: &str,
+        lang: Option<&Language>,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send;
+    fn card_by_multiverse_id(
+        &self,
+        id: i32,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send;
+    fn card_by_mtgo_id(
+        &self,
+        id: i32,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send;
+    fn card_by_arena_id(
+        &self,
+        id: i32,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send;
+    fn card_by_tcgplayer_id(
+        &self,
+        id: i32,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send;
+    fn card_by_cardmarket_id(
+        &self,
+        id: i32,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send;
+}
+
+/// An in-memory card database built from a bulk data export.
+///
+/// Load it from a `default_cards` or `oracle_cards` file (see
+/// [`ScryfallClient::bulk_data_download`]) to answer lookups without
+/// touching the network. Misses are reported as the same 404 `not_found`
+/// error the API would return.
+#[derive(Debug, Default)]
+pub struct LocalDb {
+    cards: Vec<Card>,
+    by_id: HashMap<ScryfallID, usize>,
+    by_oracle_id: HashMap<OracleID, Vec<usize>>,
+    /// lowercase full name and face names
+    by_name: HashMap<String, Vec<usize>>,
+    /// lowercase set code and collector number
+    by_set_number: HashMap<(String, String), Vec<usize>>,
+    by_multiverse_id: HashMap<i32, usize>,
+    by_mtgo_id: HashMap<i32, usize>,
+    by_arena_id: HashMap<i32, usize>,
+    by_tcgplayer_id: HashMap<i32, usize>,
+    by_cardmarket_id: HashMap<i32, usize>,
+}
+
+impl LocalDb {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_cards(cards: impl IntoIterator<Item = Card>) -> Self {
+        let mut db = Self::new();
+        for card in cards {
+            db.insert(card);
+        }
+        db
+    }
+
+    /// Builds a database from a bulk data file, decoding one card at a time.
+    pub fn from_reader(mut reader: impl Read) -> Result<Self, ScryfallClientError> {
+        let mut db = Self::new();
+        let mut decoder = JsonArrayDecoder::new();
+        let mut chunk = vec![0; 64 * 1024];
+        loop {
+            while let Some(card) = decoder.next_element() {
+                db.insert(card?);
+            }
+            let read = reader.read(&mut chunk)?;
+            if read == 0 {
+                decoder.finish()?;
+                return Ok(db);
+            }
+            decoder.push(&chunk[..read]);
+        }
+    }
+
+    /// Builds a database from a card stream, such as a bulk data download.
+    pub async fn from_stream(
+        cards: impl Stream<Item = Result<Card, ScryfallClientError>>,
+    ) -> Result<Self, ScryfallClientError> {
+        cards
+            .try_fold(Self::new(), |mut db, card| async move {
+                db.insert(card);
+                Ok(db)
+            })
+            .await
+    }
+
+    /// Adds a card to every index, replacing any card with the same ID.
+    pub fn insert(&mut self, card: Card) {
+        let index = match self.by_id.get(&card.id) {
+            Some(&index) => {
+                self.remove_from_indexes(index);
+                self.cards[index] = card;
+                index
+            }
+            None => {
+                self.cards.push(card);
+                self.cards.len() - 1
+            }
+        };
+        let card = &self.cards[index];
+
+        self.by_id.insert(card.id.clone(), index);
+        if let Some(oracle_id) = &card.oracle_id {
+            self.by_oracle_id
+                .entry(oracle_id.clone())
+                .or_default()
+                .push(index);
+        }
+        for name in names(card) {
+            self.by_name.entry(name).or_default().push(index);
+        }
+        self.by_set_number
+            .entry(set_number_key(&card.set, &card.collector_number))
+            .or_default()
+            .push(index);
+        for &id in &card.multiverse_ids {
+            self.by_multiverse_id.insert(id, index);
+        }
+        for id in [card.mtgo_id, card.mtgo_foil_id].into_iter().flatten() {
+            self.by_mtgo_id.insert(id, index);
+        }
+        if let Some(id) = card.arena_id {
+            self.by_arena_id.insert(id, index);
+        }
+        for id in [card.tcgplayer_id, card.tcgplayer_etched_id]
+            .into_iter()
+            .flatten()
+        {
+            self.by_tcgplayer_id.insert(id, index);
+        }
+        if let Some(id) = card.cardmarket_id {
+            self.by_cardmarket_id.insert(id, index);
+        }
+    }
+
+    fn remove_from_indexes(&mut self, index: usize) {
+        let card = &self.cards[index];
+        let remove = |indexes: &mut Vec<usize>| indexes.retain(|&i| i != index);
+        if let Some(oracle_id) = &card.oracle_id {
+            self.by_oracle_id.get_mut(oracle_id).map(remove);
+        }
+        for name in names(card) {
+            self.by_name.get_mut(&name).map(remove);
+        }
+        self.by_set_number
+            .get_mut(&set_number_key(&card.set, &card.collector_number))
+            .map(remove);
+        for map in [
+            &mut self.by_multiverse_id,
+            &mut self.by_mtgo_id,
+            &mut self.by_arena_id,
+            &mut self.by_tcgplayer_id,
+            &mut self.by_cardmarket_id,
+        ] {
+            map.retain(|_, &mut i| i != index);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Looks a card up by its exact name or the name of one of its faces,
+    /// ignoring case.
+    ///
+    /// Like the API, the newest English printing wins when several match.
+    pub fn card_named(&self, name: &str) -> Result<Card, ScryfallClientError> {
+        let printings = self.by_name.get(&name.to_lowercase());
+        self.best_printing(printings, None)
+            .ok_or_else(|| not_found(format!("No card found with the name {name:?}")))
+    }
+
+    pub fn card_by_id(&self, id: &ScryfallID) -> Result<Card, ScryfallClientError> {
+        self.by_id
+            .get(id)
+            .map(|&index| self.cards[index].clone())
+            .ok_or_else(|| not_found(format!("No card found with the id {id}")))
+    }
+
+    /// Every printing of the card with the given Oracle ID.
+    pub fn cards_by_oracle_id(&self, id: &OracleID) -> Vec<Card> {
+        self.by_oracle_id
+            .get(id)
+            .map(|indexes| indexes.iter().map(|&i| self.cards[i].clone()).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn card_by_set_number(
+        &self,
+        set: &str,
+        collector_number: &str,
+        lang: Option<&Language>,
+    ) -> Result<Card, ScryfallClientError> {
+        let printings = self
+            .by_set_number
+            .get(&set_number_key(set, collector_number));
+        self.best_printing(printings, lang).ok_or_else(|| {
+            not_found(format!(
+                "No card found with the set {set:?} and collector number {collector_number:?}"
+            ))
+        })
+    }
+
+    pub fn card_by_multiverse_id(&self, id: i32) -> Result<Card, ScryfallClientError> {
+        self.card_by_platform_id(&self.by_multiverse_id, "multiverse", id)
+    }
+
+    pub fn card_by_mtgo_id(&self, id: i32) -> Result<Card, ScryfallClientError> {
+        self.card_by_platform_id(&self.by_mtgo_id, "mtgo", id)
+    }
+
+    pub fn card_by_arena_id(&self, id: i32) -> Result<Card, ScryfallClientError> {
+        self.card_by_platform_id(&self.by_arena_id, "arena", id)
+    }
+
+    pub fn card_by_tcgplayer_id(&self, id: i32) -> Result<Card, ScryfallClientError> {
+        self.card_by_platform_id(&self.by_tcgplayer_id, "tcgplayer", id)
+    }
+
+    pub fn card_by_cardmarket_id(&self, id: i32) -> Result<Card, ScryfallClientError> {
+        self.card_by_platform_id(&self.by_cardmarket_id, "cardmarket", id)
+    }
+
+    fn card_by_platform_id(
+        &self,
+        index: &HashMap<i32, usize>,
+        platform: &str,
+        id: i32,
+    ) -> Result<Card, ScryfallClientError> {
+        index
+            .get(&id)
+            .map(|&index| self.cards[index].clone())
+            .ok_or_else(|| not_found(format!("No card found with the {platform} id {id}")))
+    }
+
+    /// Picks the printing in `lang` (English by default) that was released
+    /// last, falling back to any language when there is no such printing.
+    fn best_printing(
+        &self,
+        printings: Option<&Vec<usize>>,
+        lang: Option<&Language>,
+    ) -> Option<Card> {
+        let printings = printings?.iter().map(|&i| &self.cards[i]);
+        let newest = |cards: Vec<&Card>| {
+            cards
+                .into_iter()
+                .max_by(|a, b| a.released_at.cmp(&b.released_at))
+                .cloned()
+        };
+        let wanted = lang.unwrap_or(&Language::English);
+        let in_lang: Vec<_> = printings.clone().filter(|c| &c.lang == wanted).collect();
+        match lang {
+            Some(_) => newest(in_lang),
+            None => newest(in_lang).or_else(|| newest(printings.collect())),
+        }
+    }
+}
+
+fn names(card: &Card) -> Vec<String> {
+    let mut names = vec![card.name.to_lowercase()];
+    for face in card.card_faces.iter().flatten() {
+        let name = face.name.to_lowercase();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+fn set_number_key(set: &str, collector_number: &str) -> (String, String) {
+    (set.to_lowercase(), collector_number.to_lowercase())
+}
+
+fn not_found(details: String) -> ScryfallClientError {
+    ScryfallClientError::from(ScryfallError {
+        status: 404,
+        code: "not_found".to_string(),
+        details,
+        type_: None,
+        warnings: None,
+        attempts: None,
+    })
+}
+
+impl CardLookup for LocalDb {
+    fn card_named(
+        &self,
+        name: &str,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send {
+        future::ready(LocalDb::card_named(self, name))
+    }
+
+    fn card_by_id(
+        &self,
+        id: &ScryfallID,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send {
+        future::ready(LocalDb::card_by_id(self, id))
+    }
+
+    fn card_by_set_number(
+        &self,
+        set: &str,
+        collector_number: &str,
+        lang: Option<&Language>,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send {
+        future::ready(LocalDb::card_by_set_number(
+            self,
+            set,
+            collector_number,
+            lang,
+        ))
+    }
+
+    fn card_by_multiverse_id(
+        &self,
+        id: i32,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send {
+        future::ready(LocalDb::card_by_multiverse_id(self, id))
+    }
+
+    fn card_by_mtgo_id(
+        &self,
+        id: i32,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send {
+        future::ready(LocalDb::card_by_mtgo_id(self, id))
+    }
+
+    fn card_by_arena_id(
+        &self,
+        id: i32,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send {
+        future::ready(LocalDb::card_by_arena_id(self, id))
+    }
+
+    fn card_by_tcgplayer_id(
+        &self,
+        id: i32,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send {
+        future::ready(LocalDb::card_by_tcgplayer_id(self, id))
+    }
+
+    fn card_by_cardmarket_id(
+        &self,
+        id: i32,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send {
+        future::ready(LocalDb::card_by_cardmarket_id(self, id))
+    }
+}
+
+impl CardLookup for ScryfallClient {
+    fn card_named(
+        &self,
+        name: &str,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send {
+        ScryfallClient::card_named(self, name)
+    }
+
+    fn card_by_id(
+        &self,
+        id: &ScryfallID,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send {
+        ScryfallClient::card_by_id(self, id)
+    }
+
+    fn card_by_set_number(
+        &self,
+        set: &str,
+        collector_number: &str,
+        lang: Option<&Language>,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send {
+        ScryfallClient::card_by_set_number(self, set, collector_number, lang)
+    }
+
+    fn card_by_multiverse_id(
+        &self,
+        id: i32,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send {
+        ScryfallClient::card_by_multiverse_id(self, id)
+    }
+
+    fn card_by_mtgo_id(
+        &self,
+        id: i32,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send {
+        ScryfallClient::card_by_mtgo_id(self, id)
+    }
+
+    fn card_by_arena_id(
+        &self,
+        id: i32,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send {
+        ScryfallClient::card_by_arena_id(self, id)
+    }
+
+    fn card_by_tcgplayer_id(
+        &self,
+        id: i32,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send {
+        ScryfallClient::card_by_tcgplayer_id(self, id)
+    }
+
+    fn card_by_cardmarket_id(
+        &self,
+        id: i32,
+    ) -> impl Future<Output = Result<Card, ScryfallClientError>> + Send {
+        ScryfallClient::card_by_cardmarket_id(self, id)
+    }
+}
@@ -0,0 +1,604 @@
+use crate::client::collection::CardIdentifier;
+use crate::client::error::ScryfallClientError;
+use crate::client::network_types::ScryfallError;
+use crate::client::rate_limit::RateLimit;
+use crate::client::retry::RetryPolicy;
+use crate::client::scryfall_client::ScryfallClient;
+use crate::local_db::LocalDb;
+use crate::ruling::Ruling;
+use crate::set::Set;
+use crate::structs::{Card, Language, ScryfallID};
+use percent_encoding::percent_decode_str;
+use serde::Serialize;
+use serde_derive::Deserialize;
+use serde_json::{Value, json};
+use std::collections::VecDeque;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+use url::Url;
+
+/// Cards per page of `/cards/search`, as on the real API.
+const PAGE_SIZE: usize = 175;
+
+/// A failure the [`MockServer`] can be told to answer with instead of the
+/// real response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MockFault {
+    /// a 404 `not_found` error
+    NotFound,
+    /// a 404 error of type `ambiguous`, as returned for vague card names
+    Ambiguous,
+    /// a 429 `rate_limited` error with a `Retry-After` of this many seconds
+    RateLimited { retry_after: u64 },
+    /// a 200 response whose body is cut off mid-object
+    MalformedJson,
+}
+
+/// Starts a [`MockServer`] seeded with cards, sets and rulings.
+#[derive(Debug, Default)]
+pub struct MockServerBuilder {
+    cards: Vec<Card>,
+    sets: Vec<Set>,
+    rulings: Vec<Ruling>,
+}
+
+impl MockServerBuilder {
+    pub fn cards(mut self, cards: impl IntoIterator<Item = Card>) -> Self {
+        self.cards.extend(cards);
+        self
+    }
+
+    pub fn sets(mut self, sets: impl IntoIterator<Item = Set>) -> Self {
+        self.sets.extend(sets);
+        self
+    }
+
+    pub fn rulings(mut self, rulings: impl IntoIterator<Item = Ruling>) -> Self {
+        self.rulings.extend(rulings);
+        self
+    }
+
+    /// Binds to a free port on localhost and starts serving on a background
+    /// thread.
+    pub fn start(self) -> io::Result<MockServer> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(State {
+            base_url: Url::parse(&format!("http://{addr}/")).unwrap(),
+            db: LocalDb::from_cards(self.cards),
+            sets: self.sets,
+            rulings: self.rulings,
+            faults: Mutex::new(Faults::default()),
+            requests: AtomicUsize::new(0),
+            stop: AtomicBool::new(false),
+        });
+        let thread = {
+            let state = state.clone();
+            std::thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if state.stop.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    if let Ok(stream) = stream {
+                        // a broken connection only fails that request
+                        let _ = state.serve(stream);
+                    }
+                }
+            })
+        };
+        Ok(MockServer {
+            addr,
+            state,
+            thread: Some(thread),
+        })
+    }
+}
+
+/// A local stand-in for the Scryfall API, for hermetic integration tests.
+///
+/// Serves the card (by ID, set and collector number, or platform ID), named,
+/// random, search, collection, rulings and sets endpoints from the cards it
+/// was seeded with, plus Scryfall-style errors for anything it doesn't know.
+/// Search, and the `q` filter of random, only understand plain words, which
+/// must all appear in a card's name or type line.
+///
+/// Faults such as rate limits or malformed JSON can be injected with
+/// [`MockServer::fail_next`] and [`MockServer::fail_always`]. The server
+/// stops when dropped.
+///
+/// Only available with the `mock` feature.
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<State>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    pub fn builder() -> MockServerBuilder {
+        MockServerBuilder::default()
+    }
+
+    /// Starts a server seeded with `cards` only.
+    pub fn start(cards: impl IntoIterator<Item = Card>) -> io::Result<Self> {
+        Self::builder().cards(cards).start()
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// The URL to use as a [`ScryfallClient`]'s base URL.
+    pub fn base_url(&self) -> Url {
+        self.state.base_url.clone()
+    }
+
+    /// A client pointed at this server, without rate limiting or retries so
+    /// tests run fast and see injected faults directly.
+    pub fn client(&self) -> ScryfallClient {
+        ScryfallClient::builder("scryfall-rs-mock")
+            .base_url(self.base_url())
+            .rate_limit(RateLimit::new(Duration::ZERO))
+            .retry(RetryPolicy::none())
+            .build()
+    }
+
+    /// Answers the next request with `fault`. Calls queue up, one fault per
+    /// request.
+    pub fn fail_next(&self, fault: MockFault) {
+        self.state.faults().queue.push_back(fault);
+    }
+
+    /// Answers every request with `fault` until called again with `None`.
+    /// Faults queued with [`MockServer::fail_next`] still come first.
+    pub fn fail_always(&self, fault: Option<MockFault>) {
+        self.state.faults().always = fault;
+    }
+
+    /// Number of requests served so far.
+    pub fn request_count(&self) -> usize {
+        self.state.requests.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.state.stop.store(true, Ordering::SeqCst);
+        // wake the accept loop so it notices the stop flag
+        let _ = TcpStream::connect(self.addr);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Faults {
+    queue: VecDeque<MockFault>,
+    always: Option<MockFault>,
+}
+
+struct State {
+    base_url: Url,
+    db: LocalDb,
+    sets: Vec<Set>,
+    rulings: Vec<Ruling>,
+    faults: Mutex<Faults>,
+    requests: AtomicUsize,
+    stop: AtomicBool,
+}
+
+struct Response {
+    status: u16,
+    body: Vec<u8>,
+    retry_after: Option<u64>,
+}
+
+impl Response {
+    fn json(status: u16, value: &impl Serialize) -> Self {
+        Response {
+            status,
+            body: serde_json::to_vec(value).expect("mock responses always serialize"),
+            retry_after: None,
+        }
+    }
+
+    fn ok(value: &impl Serialize) -> Self {
+        Self::json(200, value)
+    }
+
+    fn error(status: u16, code: &str, details: &str) -> Self {
+        Self::json(
+            status,
+            &json!({ "object": "error", "code": code, "status": status, "details": details }),
+        )
+    }
+
+    fn ambiguous(details: &str) -> Self {
+        Self::json(
+            404,
+            &json!({ "object": "error", "code": "not_found", "type": "ambiguous", "status": 404, "details": details }),
+        )
+    }
+
+    fn from_error(err: ScryfallClientError) -> Self {
+        let Some(err) = err.api_error().cloned() else {
+            return Self::error(500, "internal_error", &err.to_string());
+        };
+        let mut value = json!({ "object": "error", "code": err.code, "status": err.status, "details": err.details });
+        if let Some(type_) = err.type_ {
+            value["type"] = Value::String(type_.into());
+        }
+        Self::json(err.status, &value)
+    }
+
+    fn list<T: Serialize>(data: &[T]) -> Self {
+        Self::ok(&json!({ "object": "list", "has_more": false, "data": data }))
+    }
+
+    fn fault(fault: &MockFault) -> Self {
+        match fault {
+            MockFault::NotFound => Self::error(404, "not_found", "Injected not found error"),
+            MockFault::Ambiguous => Self::ambiguous("Injected ambiguous name error"),
+            MockFault::RateLimited { retry_after } => Response {
+                retry_after: Some(*retry_after),
+                ..Self::error(429, "rate_limited", "Injected rate limit error")
+            },
+            MockFault::MalformedJson => Response {
+                status: 200,
+                body: br#"{"object": "card", "name": "#.to_vec(),
+                retry_after: None,
+            },
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct CollectionRequest {
+    identifiers: Vec<CardIdentifier>,
+}
+
+impl State {
+    fn faults(&self) -> std::sync::MutexGuard<'_, Faults> {
+        self.faults.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn serve(&self, mut stream: TcpStream) -> io::Result<()> {
+        stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+        let mut parts = request_line.split_whitespace();
+        let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+            // e.g. the wake-up connection made when the server is dropped
+            return Ok(());
+        };
+        let mut content_length = 0;
+        loop {
+            let mut header = String::new();
+            reader.read_line(&mut header)?;
+            let header = header.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':')
+                && name.eq_ignore_ascii_case("content-length")
+            {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+        }
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body)?;
+
+        self.requests.fetch_add(1, Ordering::SeqCst);
+        let response = self.respond(method, target, &body);
+
+        let mut head = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n",
+            response.status,
+            reason(response.status),
+            response.body.len()
+        );
+        if let Some(retry_after) = response.retry_after {
+            head.push_str(&format!("Retry-After: {retry_after}\r\n"));
+        }
+        head.push_str("\r\n");
+        stream.write_all(head.as_bytes())?;
+        stream.write_all(&response.body)?;
+        stream.flush()
+    }
+
+    fn respond(&self, method: &str, target: &str, body: &[u8]) -> Response {
+        let fault = {
+            let mut faults = self.faults();
+            faults.queue.pop_front().or_else(|| faults.always.clone())
+        };
+        if let Some(fault) = fault {
+            return Response::fault(&fault);
+        }
+        let Ok(url) = self.base_url.join(target.trim_start_matches('/')) else {
+            return Response::error(400, "bad_request", "Malformed request target");
+        };
+        let segments: Vec<String> = url
+            .path_segments()
+            .into_iter()
+            .flatten()
+            .map(|segment| percent_decode_str(segment).decode_utf8_lossy().into_owned())
+            .collect();
+        let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+        let query = |name: &str| {
+            url.query_pairs()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.into_owned())
+        };
+
+        match (method, segments.as_slice()) {
+            ("POST", ["cards", "collection"]) => self.collection(body),
+            (_, ["cards", "collection"]) => {
+                Response::error(405, "method_not_allowed", "Use POST for /cards/collection")
+            }
+            ("GET", ["cards", "named"]) => self.named(query("exact"), query("fuzzy"), query("set")),
+            ("GET", ["cards", "random"]) => self.random(query("q")),
+            ("GET", ["cards", "search"]) => {
+                let page = query("page").and_then(|p| p.parse().ok()).unwrap_or(1);
+                self.search(&query("q").unwrap_or_default(), page)
+            }
+            ("GET", ["cards", rest @ .., "rulings"]) => match self.card(rest) {
+                Ok(card) => {
+                    let rulings: Vec<_> = self
+                        .rulings
+                        .iter()
+                        .filter(|ruling| Some(&ruling.oracle_id) == card.oracle_id.as_ref())
+                        .collect();
+                    Response::list(&rulings)
+                }
+                Err(err) => Response::from_error(err),
+            },
+            ("GET", ["cards", rest @ ..]) => match self.card(rest) {
+                Ok(card) => Response::ok(&card),
+                Err(err) => Response::from_error(err),
+            },
+            ("GET", ["sets"]) => Response::list(&self.sets),
+            ("GET", ["sets", code]) => self
+                .sets
+                .iter()
+                .find(|set| set.code.eq_ignore_ascii_case(code) || set.id.to_string() == *code)
+                .map(Response::ok)
+                .unwrap_or_else(|| {
+                    Response::error(404, "not_found", &format!("No set found for code {code:?}"))
+                }),
+            ("GET", _) | ("POST", _) => Response::error(
+                404,
+                "not_found",
+                &format!("The mock server does not implement {}", url.path()),
+            ),
+            _ => Response::error(405, "method_not_allowed", "Only GET and POST are supported"),
+        }
+    }
+
+    /// Resolves the segments after `/cards/`.
+    fn card(&self, segments: &[&str]) -> Result<Card, ScryfallClientError> {
+        let platform_id = |id: &str| -> Result<i32, ScryfallClientError> {
+            id.parse()
+                .map_err(|_| not_found(format!("Invalid id {id:?}")))
+        };
+        match segments {
+            ["multiverse", id] => self.db.card_by_multiverse_id(platform_id(id)?),
+            ["mtgo", id] => self.db.card_by_mtgo_id(platform_id(id)?),
+            ["arena", id] => self.db.card_by_arena_id(platform_id(id)?),
+            ["tcgplayer", id] => self.db.card_by_tcgplayer_id(platform_id(id)?),
+            ["cardmarket", id] => self.db.card_by_cardmarket_id(platform_id(id)?),
+            [id] => match id.parse() {
+                Ok(id) => self.db.card_by_id(&ScryfallID(id)),
+                Err(_) => Err(not_found(format!("Invalid card id {id:?}"))),
+            },
+            [set, number] => self.db.card_by_set_number(set, number, None),
+            [set, number, lang] => {
+                let lang: Language = serde_json::from_value(Value::String(lang.to_string()))
+                    .map_err(|_| not_found(format!("Unknown language {lang:?}")))?;
+                self.db.card_by_set_number(set, number, Some(&lang))
+            }
+            _ => Err(not_found(format!(
+                "No card found at /cards/{}",
+                segments.join("/")
+            ))),
+        }
+    }
+
+    fn named(&self, exact: Option<String>, fuzzy: Option<String>, set: Option<String>) -> Response {
+        let in_set = |card: &Card| {
+            set.as_ref()
+                .is_none_or(|set| card.set.eq_ignore_ascii_case(set))
+        };
+        let (name, fuzzy) = match (exact, fuzzy) {
+            (Some(name), _) => (name, false),
+            (None, Some(name)) => (name, true),
+            (None, None) => {
+                return Response::error(400, "bad_request", "Pass either exact or fuzzy");
+            }
+        };
+        let wanted = name.to_lowercase();
+        let names_of = |card: &Card| {
+            let mut names = vec![card.name.to_lowercase()];
+            names.extend(
+                card.card_faces
+                    .iter()
+                    .flatten()
+                    .map(|face| face.name.to_lowercase()),
+            );
+            names
+        };
+        let mut matches: Vec<&Card> = self
+            .db
+            .cards()
+            .iter()
+            .filter(|card| in_set(card) && names_of(card).contains(&wanted))
+            .collect();
+        if matches.is_empty() && fuzzy {
+            let words: Vec<_> = wanted.split_whitespace().collect();
+            matches = self
+                .db
+                .cards()
+                .iter()
+                .filter(|card| {
+                    let name = card.name.to_lowercase();
+                    in_set(card) && words.iter().all(|word| name.contains(word))
+                })
+                .collect();
+            let mut names: Vec<_> = matches.iter().map(|card| &card.name).collect();
+            names.sort();
+            names.dedup();
+            if names.len() > 1 {
+                return Response::ambiguous(&format!(
+                    "Too many cards match ambiguous name “{name}”. Add more words to refine your search."
+                ));
+            }
+        }
+        match matches
+            .into_iter()
+            .max_by(|a, b| a.released_at.cmp(&b.released_at))
+        {
+            Some(card) => Response::ok(card),
+            None => Response::error(
+                404,
+                "not_found",
+                &format!("No cards found matching “{name}”"),
+            ),
+        }
+    }
+
+    fn random(&self, q: Option<String>) -> Response {
+        let matches = match q {
+            Some(q) => self.matching(&q),
+            None => self.db.cards().iter().collect(),
+        };
+        if matches.is_empty() {
+            return Response::error(404, "not_found", "Your query didn’t match any cards.");
+        }
+        Response::ok(matches[fastrand::usize(..matches.len())])
+    }
+
+    /// The cards whose name or type line contains every word of `q`.
+    fn matching(&self, q: &str) -> Vec<&Card> {
+        let words: Vec<_> = q.split_whitespace().map(str::to_lowercase).collect();
+        self.db
+            .cards()
+            .iter()
+            .filter(|card| {
+                let text = format!(
+                    "{} {}",
+                    card.name.to_lowercase(),
+                    card.type_line.as_deref().unwrap_or_default().to_lowercase()
+                );
+                words.iter().all(|word| text.contains(word))
+            })
+            .collect()
+    }
+
+    fn search(&self, q: &str, page: usize) -> Response {
+        let matches = self.matching(q);
+        if matches.is_empty() {
+            return Response::error(404, "not_found", "Your query didn’t match any cards.");
+        }
+        let start = (page.max(1) - 1) * PAGE_SIZE;
+        let data: Vec<_> = matches.iter().skip(start).take(PAGE_SIZE).collect();
+        let has_more = start + PAGE_SIZE < matches.len();
+        let mut value = json!({
+            "object": "list",
+            "total_cards": matches.len(),
+            "has_more": has_more,
+            "data": data,
+        });
+        if has_more {
+            let mut next = self.base_url.join("cards/search").unwrap();
+            next.query_pairs_mut()
+                .append_pair("q", q)
+                .append_pair("page", &(page.max(1) + 1).to_string());
+            value["next_page"] = Value::String(next.to_string());
+        }
+        Response::ok(&value)
+    }
+
+    fn collection(&self, body: &[u8]) -> Response {
+        let request: CollectionRequest = match serde_json::from_slice(body) {
+            Ok(request) => request,
+            Err(err) => return Response::error(400, "bad_request", &err.to_string()),
+        };
+        if request.identifiers.len() > CardIdentifier::MAX_PER_REQUEST {
+            return Response::error(
+                422,
+                "validation_error",
+                "Too many identifiers in one request",
+            );
+        }
+        let mut cards = Vec::new();
+        let mut not_found = Vec::new();
+        for identifier in request.identifiers {
+            match self.identify(&identifier) {
+                Some(card) => cards.push(card),
+                None => not_found.push(identifier),
+            }
+        }
+        Response::ok(&json!({
+            "object": "list",
+            "has_more": false,
+            "not_found": not_found,
+            "data": cards,
+        }))
+    }
+
+    fn identify(&self, identifier: &CardIdentifier) -> Option<Card> {
+        let cards = self.db.cards();
+        match identifier {
+            CardIdentifier::Id { id } => self.db.card_by_id(id).ok(),
+            CardIdentifier::MtgoId { mtgo_id } => self.db.card_by_mtgo_id(*mtgo_id).ok(),
+            CardIdentifier::MultiverseId { multiverse_id } => {
+                self.db.card_by_multiverse_id(*multiverse_id).ok()
+            }
+            CardIdentifier::OracleId { oracle_id } => {
+                self.db.cards_by_oracle_id(oracle_id).into_iter().next()
+            }
+            CardIdentifier::IllustrationId { illustration_id } => cards
+                .iter()
+                .find(|card| card.illustration_id.as_deref() == Some(&illustration_id.to_string()))
+                .cloned(),
+            CardIdentifier::NameSet { name, set } => cards
+                .iter()
+                .find(|card| {
+                    card.name.eq_ignore_ascii_case(name) && card.set.eq_ignore_ascii_case(set)
+                })
+                .cloned(),
+            CardIdentifier::Name { name } => self.db.card_named(name).ok(),
+            CardIdentifier::SetCollectorNumber {
+                set,
+                collector_number,
+            } => self.db.card_by_set_number(set, collector_number, None).ok(),
+        }
+    }
+}
+
+fn not_found(details: String) -> ScryfallClientError {
+    ScryfallClientError::from(ScryfallError {
+        status: 404,
+        code: "not_found".to_string(),
+        details,
+        type_: None,
+        warnings: None,
+        attempts: None,
+    })
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        422 => "Unprocessable Entity",
+        429 => "Too Many Requests",
+        _ => "Internal Server Error",
+    }
+}
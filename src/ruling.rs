@@ -0,0 +1,33 @@
+use crate::client::error::ScryfallClientError;
+use crate::client::scryfall_client::ScryfallClient;
+use crate::structs::{Card, OracleID};
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RulingSource {
+    #[serde(rename = "wotc")]
+    Wotc,
+    #[serde(rename = "scryfall")]
+    Scryfall,
+}
+
+/// An official or Scryfall-authored note on how a card works.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Ruling {
+    /// always "ruling"
+    pub object: String,
+    pub oracle_id: OracleID,
+    pub source: RulingSource,
+    pub published_at: String, //convert to date using external crate??
+    pub comment: String,
+}
+
+impl Card {
+    /// Fetches the rulings for this card by following [`Card::rulings_uri`].
+    pub async fn rulings(
+        &self,
+        client: &ScryfallClient,
+    ) -> Result<Vec<Ruling>, ScryfallClientError> {
+        client.rulings_at(self.rulings_uri.as_str()).await
+    }
+}
@@ -0,0 +1,45 @@
+use crate::structs::SetType;
+use serde_derive::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use url::Url;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SetID(pub Uuid);
+
+impl Display for SetID {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A group of related cards released together, such as an expansion or a
+/// promo series.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Set {
+    pub id: SetID,
+    /// always "set"
+    pub object: String,
+    pub code: String,
+    pub mtgo_code: Option<String>,
+    pub arena_code: Option<String>,
+    pub tcgplayer_id: Option<i32>,
+    pub name: String,
+    pub set_type: SetType,
+    pub released_at: Option<String>,
+    pub block_code: Option<String>,
+    pub block: Option<String>,
+    pub parent_set_code: Option<String>,
+    pub card_count: u32,
+    pub printed_size: Option<u32>,
+    pub digital: bool,
+    pub foil_only: bool,
+    pub nonfoil_only: bool,
+    /// link to the set on scryfall's website
+    pub scryfall_uri: Url,
+    /// link to the set on scryfall's API
+    pub uri: Url,
+    pub icon_svg_uri: Url,
+    /// search for every card in the set, see `ScryfallClient::card_list_stream`
+    pub search_uri: Url,
+}
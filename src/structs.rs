@@ -77,6 +77,32 @@ pub enum Language {
     Quenya,
 }
 
+impl Language {
+    /// The language code Scryfall uses in URLs and the `lang` field.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+            Language::French => "fr",
+            Language::German => "de",
+            Language::Italian => "it",
+            Language::Portuguese => "pt",
+            Language::Japanese => "ja",
+            Language::Korean => "ko",
+            Language::Russian => "ru",
+            Language::SimplifiedChinese => "zhs",
+            Language::TraditionalChinese => "zht",
+            Language::Hebrew => "he",
+            Language::Latin => "la",
+            Language::AncientGreek => "grc",
+            Language::Arabic => "ar",
+            Language::Sanskrit => "sa",
+            Language::Phyrexian => "ph",
+            Language::Quenya => "qya",
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Layout {
     #[serde(rename = "normal")]
@@ -341,9 +367,117 @@ pub enum SecurityStamp {
     Heart,
 }
 
+/// The kind of product a set was released as.
+///
+/// Scryfall adds set types over time; anything not listed here is kept as
+/// [`SetType::Unknown`] instead of failing to deserialize.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum SetType {
-    Unimplemented,
+    Core,
+    Expansion,
+    Masters,
+    Eternal,
+    Alchemy,
+    Masterpiece,
+    Arsenal,
+    FromTheVault,
+    Spellbook,
+    PremiumDeck,
+    DuelDeck,
+    DraftInnovation,
+    TreasureChest,
+    Commander,
+    Planechase,
+    Archenemy,
+    Vanguard,
+    Funny,
+    Starter,
+    Box,
+    Promo,
+    Token,
+    Memorabilia,
+    Minigame,
+    Unknown(String),
+}
+
+impl SetType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            SetType::Core => "core",
+            SetType::Expansion => "expansion",
+            SetType::Masters => "masters",
+            SetType::Eternal => "eternal",
+            SetType::Alchemy => "alchemy",
+            SetType::Masterpiece => "masterpiece",
+            SetType::Arsenal => "arsenal",
+            SetType::FromTheVault => "from_the_vault",
+            SetType::Spellbook => "spellbook",
+            SetType::PremiumDeck => "premium_deck",
+            SetType::DuelDeck => "duel_deck",
+            SetType::DraftInnovation => "draft_innovation",
+            SetType::TreasureChest => "treasure_chest",
+            SetType::Commander => "commander",
+            SetType::Planechase => "planechase",
+            SetType::Archenemy => "archenemy",
+            SetType::Vanguard => "vanguard",
+            SetType::Funny => "funny",
+            SetType::Starter => "starter",
+            SetType::Box => "box",
+            SetType::Promo => "promo",
+            SetType::Token => "token",
+            SetType::Memorabilia => "memorabilia",
+            SetType::Minigame => "minigame",
+            SetType::Unknown(set_type) => set_type,
+        }
+    }
+}
+
+impl From<String> for SetType {
+    fn from(set_type: String) -> Self {
+        match set_type.as_str() {
+            "core" => SetType::Core,
+            "expansion" => SetType::Expansion,
+            "masters" => SetType::Masters,
+            "eternal" => SetType::Eternal,
+            "alchemy" => SetType::Alchemy,
+            "masterpiece" => SetType::Masterpiece,
+            "arsenal" => SetType::Arsenal,
+            "from_the_vault" => SetType::FromTheVault,
+            "spellbook" => SetType::Spellbook,
+            "premium_deck" => SetType::PremiumDeck,
+            "duel_deck" => SetType::DuelDeck,
+            "draft_innovation" => SetType::DraftInnovation,
+            "treasure_chest" => SetType::TreasureChest,
+            "commander" => SetType::Commander,
+            "planechase" => SetType::Planechase,
+            "archenemy" => SetType::Archenemy,
+            "vanguard" => SetType::Vanguard,
+            "funny" => SetType::Funny,
+            "starter" => SetType::Starter,
+            "box" => SetType::Box,
+            "promo" => SetType::Promo,
+            "token" => SetType::Token,
+            "memorabilia" => SetType::Memorabilia,
+            "minigame" => SetType::Minigame,
+            _ => SetType::Unknown(set_type),
+        }
+    }
+}
+
+impl From<SetType> for String {
+    fn from(set_type: SetType) -> Self {
+        match set_type {
+            SetType::Unknown(set_type) => set_type,
+            set_type => set_type.as_str().to_string(),
+        }
+    }
+}
+
+impl Display for SetType {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -486,7 +620,7 @@ pub struct Card {
     pub scryfall_set_uri: Url,
     pub set_name: String,
     pub set_search_uri: Url,
-    pub set_type: String, //convert to SetType enum when we have comphrehensive list of set types
+    pub set_type: SetType,
     pub set_uri: Url,
     pub set: String,
     pub set_id: String,
@@ -0,0 +1,39 @@
+use crate::structs::Colors;
+use serde_derive::{Deserialize, Serialize};
+use url::Url;
+
+/// A symbol that can appear in mana costs or rules text, e.g. `{T}` or `{W/U}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CardSymbol {
+    /// always "card_symbol"
+    pub object: String,
+    pub symbol: String,
+    pub svg_uri: Option<Url>,
+    /// alternate form of the symbol that Scryfall also accepts, e.g. `{P}` for `{W/P}`
+    pub loose_variant: Option<String>,
+    pub english: String,
+    pub transposable: bool,
+    pub represents_mana: bool,
+    pub mana_value: Option<f32>,
+    pub appears_in_mana_costs: bool,
+    pub funny: bool,
+    pub colors: Vec<Colors>,
+    pub hybrid: bool,
+    pub phyrexian: bool,
+    /// other ways this symbol was printed in Gatherer, e.g. `oW` for `{W}`
+    pub gatherer_alternates: Option<Vec<String>>,
+}
+
+/// A mana cost parsed and normalized by Scryfall.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManaCost {
+    /// always "mana_cost"
+    pub object: String,
+    /// the normalized cost, e.g. `{2}{G}{G}` for `GG2`
+    pub cost: String,
+    pub cmc: f32,
+    pub colors: Vec<Colors>,
+    pub colorless: bool,
+    pub monocolored: bool,
+    pub multicolored: bool,
+}
@@ -0,0 +1,39 @@
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the functionality of the `autocomplete` function
+/// by requesting suggestions for a partial card name.
+/// It expects a catalog of at most 20 matching names.
+async fn test_autocomplete() {
+    use scryfall_rs::ScryfallClient;
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let catalog = match client.autocomplete("grimgrin", false).await {
+        Ok(catalog) => catalog,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to autocomplete 'grimgrin'");
+        }
+    };
+    assert_eq!(catalog.object, "catalog");
+    assert!(catalog.data.len() <= 20);
+    assert!(catalog.data.contains(&"Grimgrin, Corpse-Born".to_string()));
+}
+
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the `autocomplete` function with a string too short to match.
+/// It expects an empty catalog rather than an error.
+async fn test_autocomplete_too_short() {
+    use scryfall_rs::ScryfallClient;
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let catalog = match client.autocomplete("g", false).await {
+        Ok(catalog) => catalog,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to autocomplete 'g'");
+        }
+    };
+    assert_eq!(catalog.total_values, 0);
+    assert!(catalog.data.is_empty());
+}
@@ -0,0 +1,116 @@
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the functionality of the `bulk_data` and `bulk_data_by_type` functions
+/// by listing every bulk export and then requesting one by type.
+/// It expects the same export from both calls.
+async fn test_bulk_data() {
+    use scryfall_rs::{BulkDataType, ScryfallClient};
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let exports = match client.bulk_data().await {
+        Ok(exports) => exports,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to list bulk data");
+        }
+    };
+    let oracle_cards = exports
+        .iter()
+        .find(|export| export.type_ == BulkDataType::OracleCards)
+        .expect("oracle_cards is a bulk data type");
+
+    let by_type = client
+        .bulk_data_by_type(BulkDataType::OracleCards)
+        .await
+        .expect("Failed to fetch oracle_cards bulk data");
+    assert_eq!(by_type.id, oracle_cards.id);
+}
+
+#[test]
+/// This test checks that bulk export types Scryfall adds in the future still deserialize.
+/// It expects unrecognised values to round-trip through `BulkDataType::Unknown`.
+fn test_bulk_data_type_unknown() {
+    use scryfall_rs::BulkDataType;
+
+    let type_: BulkDataType = serde_json::from_str("\"unique_artwork\"").unwrap();
+    assert_eq!(type_, BulkDataType::UniqueArtwork);
+    assert_eq!(type_.path(), "unique-artwork");
+    let type_: BulkDataType = serde_json::from_str("\"all_sets\"").unwrap();
+    assert_eq!(type_, BulkDataType::Unknown("all_sets".to_string()));
+    assert_eq!(type_.path(), "all-sets");
+    assert_eq!(serde_json::to_string(&type_).unwrap(), "\"all_sets\"");
+}
+
+#[tokio::test]
+#[ignore = "Downloads the full oracle_cards export (over 100 MB)"]
+/// This test checks the functionality of the `bulk_data_download` function
+/// by streaming the oracle_cards export.
+/// It expects tens of thousands of cards, decoded one at a time.
+async fn test_bulk_data_download() {
+    use futures::TryStreamExt;
+    use scryfall_rs::{BulkDataType, Card, ScryfallClient};
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let oracle_cards = client
+        .bulk_data_by_type(BulkDataType::OracleCards)
+        .await
+        .expect("Failed to fetch oracle_cards bulk data");
+    let count = client
+        .bulk_data_download::<Card>(&oracle_cards)
+        .try_fold(0, |count, card| async move {
+            assert!(!card.name.is_empty());
+            Ok(count + 1)
+        })
+        .await
+        .expect("Failed to download oracle_cards");
+    assert!(count > 25000);
+}
+
+#[tokio::test]
+/// This test checks that `decode_json_array` copes with elements split across chunks,
+/// including strings that contain brackets and escaped quotes.
+/// It expects every element back in order.
+async fn test_decode_json_array_chunked() {
+    use futures::{TryStreamExt, stream};
+    use scryfall_rs::{Ruling, RulingSource, ScryfallClientError, decode_json_array};
+
+    let json = r#" [
+        {"object":"ruling","oracle_id":"5089ec1a-f881-4d55-af14-5d996171203b","source":"wotc","published_at":"2004-10-04","comment":"A \"quoted\" [bracket] {brace}"},
+        {"object":"ruling","oracle_id":"5089ec1a-f881-4d55-af14-5d996171203b","source":"scryfall","published_at":"2020-01-01","comment":"Ends with a backslash \\"}
+    ] "#;
+    for chunk_size in [1, 3, 7, 64, json.len()] {
+        let chunks = json
+            .as_bytes()
+            .chunks(chunk_size)
+            .map(Ok::<_, std::io::Error>)
+            .collect::<Vec<_>>();
+        let rulings: Vec<Ruling> = decode_json_array(stream::iter(chunks))
+            .try_collect()
+            .await
+            .unwrap_or_else(|err: ScryfallClientError| panic!("chunk size {chunk_size}: {err}"));
+        assert_eq!(rulings.len(), 2);
+        assert_eq!(rulings[0].source, RulingSource::Wotc);
+        assert_eq!(rulings[0].comment, "A \"quoted\" [bracket] {brace}");
+        assert_eq!(rulings[1].comment, "Ends with a backslash \\");
+    }
+}
+
+#[tokio::test]
+/// This test checks the error handling of `decode_json_array`
+/// by feeding it an array that is cut off mid-element.
+/// It expects the decoded elements followed by an error.
+async fn test_decode_json_array_truncated() {
+    use futures::{StreamExt, stream};
+    use scryfall_rs::{Ruling, ScryfallClientError, decode_json_array};
+
+    let json = r#"[{"object":"ruling","oracle_id":"5089ec1a-f881-4d55-af14-5d996171203b","source":"wotc","published_at":"2004-10-04","comment":"ok"},{"object":"rul"#;
+    let chunks = vec![Ok::<_, std::io::Error>(json.as_bytes())];
+    let results: Vec<Result<Ruling, ScryfallClientError>> =
+        decode_json_array(stream::iter(chunks)).collect().await;
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_ok());
+    assert!(matches!(
+        results[1],
+        Err(ScryfallClientError::Deserialize { .. })
+    ));
+}
@@ -0,0 +1,147 @@
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the functionality of the `card_by_id` function
+/// by requesting Black Lotus from Limited Edition Alpha by its Scryfall ID.
+/// It expects a successful response from the Scryfall API.
+async fn test_card_by_id() {
+    use scryfall_rs::{ScryfallClient, ScryfallID};
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let id = ScryfallID("bd8fa327-dd41-4737-8f19-2cf5eb1f7cdd".parse().unwrap());
+    let card = match client.card_by_id(&id).await {
+        Ok(card) => card,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to fetch card by id {}", id);
+        }
+    };
+    assert_eq!(card.name, "Black Lotus");
+    assert_eq!(card.id, id);
+}
+
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the functionality of the `card_by_set_number` function
+/// by requesting a printing by its set code and collector number.
+/// It expects a successful response from the Scryfall API.
+async fn test_card_by_set_number() {
+    use scryfall_rs::ScryfallClient;
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let card = match client.card_by_set_number("lea", "232", None).await {
+        Ok(card) => card,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to fetch LEA #232");
+        }
+    };
+    assert_eq!(card.name, "Black Lotus");
+    assert_eq!(card.collector_number, "232");
+}
+
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the language handling of the `card_by_set_number` function
+/// by requesting the Japanese printing of a card.
+/// It expects the returned card to be in the requested language.
+async fn test_card_by_set_number_language() {
+    use scryfall_rs::{Language, ScryfallClient};
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let client_card = client
+        .card_by_set_number("war", "1", Some(&Language::Japanese))
+        .await;
+    let card = match client_card {
+        Ok(card) => card,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to fetch Japanese WAR #1");
+        }
+    };
+    assert_eq!(card.lang, Language::Japanese);
+    assert!(card.printed_name.is_some());
+}
+
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the error handling of the `card_by_set_number` function
+/// by requesting a collector number that does not exist.
+/// It expects a 404 error response from the Scryfall API.
+async fn test_card_by_set_number_error() {
+    use scryfall_rs::ScryfallClient;
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let err = match client.card_by_set_number("lea", "9999", None).await {
+        Ok(_) => panic!("Successfully fetched a card that does not exist"),
+        Err(err) => err,
+    };
+    assert_eq!(err.status(), Some(404));
+}
+
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the `card_by_*_id` functions for every external platform
+/// by resolving IDs taken from a card fetched by name.
+/// It expects each lookup to return a printing with the same ID.
+async fn test_card_by_platform_ids() {
+    use scryfall_rs::ScryfallClient;
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let card = client
+        .card_by_set_number("m21", "183", None)
+        .await
+        .expect("Failed to fetch M21 #183");
+
+    let multiverse_id = card.multiverse_ids[0];
+    let by_multiverse = client
+        .card_by_multiverse_id(multiverse_id)
+        .await
+        .expect("Failed to fetch card by multiverse id");
+    assert!(by_multiverse.multiverse_ids.contains(&multiverse_id));
+
+    let mtgo_id = card.mtgo_id.expect("M21 printing has an MTGO id");
+    let by_mtgo = client
+        .card_by_mtgo_id(mtgo_id)
+        .await
+        .expect("Failed to fetch card by mtgo id");
+    assert_eq!(by_mtgo.mtgo_id, Some(mtgo_id));
+
+    let arena_id = card.arena_id.expect("M21 printing has an Arena id");
+    let by_arena = client
+        .card_by_arena_id(arena_id)
+        .await
+        .expect("Failed to fetch card by arena id");
+    assert_eq!(by_arena.arena_id, Some(arena_id));
+
+    let tcgplayer_id = card.tcgplayer_id.expect("M21 printing has a TCGplayer id");
+    let by_tcgplayer = client
+        .card_by_tcgplayer_id(tcgplayer_id)
+        .await
+        .expect("Failed to fetch card by tcgplayer id");
+    assert_eq!(by_tcgplayer.tcgplayer_id, Some(tcgplayer_id));
+
+    let cardmarket_id = card
+        .cardmarket_id
+        .expect("M21 printing has a Cardmarket id");
+    let by_cardmarket = client
+        .card_by_cardmarket_id(cardmarket_id)
+        .await
+        .expect("Failed to fetch card by cardmarket id");
+    assert_eq!(by_cardmarket.cardmarket_id, Some(cardmarket_id));
+}
+
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the error handling of the `card_by_arena_id` function
+/// by requesting an Arena ID that does not exist.
+/// It expects a 404 error response from the Scryfall API.
+async fn test_card_by_arena_id_error() {
+    use scryfall_rs::ScryfallClient;
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let err = match client.card_by_arena_id(i32::MAX).await {
+        Ok(_) => panic!("Successfully fetched a card that does not exist"),
+        Err(err) => err,
+    };
+    assert_eq!(err.status(), Some(404));
+}
@@ -1,13 +1,28 @@
 use core::panic;
 
+/// Builds a client that replays the responses recorded in
+/// `tests/fixtures/card_named`, so these tests run offline.
+/// Set `SCRYFALL_VCR=record` to re-record them from the live API.
+fn vcr_client() -> scryfall_rs::ScryfallClient {
+    use scryfall_rs::client::http::HttpClient;
+    use scryfall_rs::{ScryfallClient, VcrTransport};
+
+    let dir = "tests/fixtures/card_named";
+    let transport = match std::env::var("SCRYFALL_VCR").as_deref() {
+        Ok("record") => VcrTransport::record(dir, HttpClient::new("scryfall-rs")),
+        _ => VcrTransport::replay(dir),
+    };
+    ScryfallClient::builder("scryfall-rs")
+        .transport(transport)
+        .build()
+}
+
 #[tokio::test]
 /// This test checks the functionality of the `card_named` function
 /// by requesting a card by its name.
 /// It expects a successful response from the Scryfall API.
 async fn test_card_named() {
-    use scryfall_rs::ScryfallClient;
-
-    let mut client = ScryfallClient::new("scryfall-rs");
+    let client = vcr_client();
     let client_card = client.card_named("Black Lotus").await;
     let card = match client_card {
         Ok(card) => card,
@@ -24,9 +39,7 @@ async fn test_card_named() {
 /// by requesting a card that does not exist.
 /// It expects a 404 error response from the Scryfall API.
 async fn test_card_named_error() {
-    use scryfall_rs::ScryfallClient;
-
-    let mut client = ScryfallClient::new("scryfall-rs");
+    let client = vcr_client();
     let client_card = client.card_named("Nonexistent Card").await;
     let err = match client_card {
         Ok(_) => {
@@ -35,7 +48,7 @@ async fn test_card_named_error() {
         }
         Err(err) => err,
     };
-    assert_eq!(err.status, 404);
+    assert_eq!(err.status(), Some(404));
 }
 
 #[tokio::test]
@@ -43,9 +56,7 @@ async fn test_card_named_error() {
 /// by requesting a card with an invalid name.
 /// It expects a 404 error response from the Scryfall API.
 async fn test_card_named_invalid_name() {
-    use scryfall_rs::ScryfallClient;
-
-    let mut client = ScryfallClient::new("scryfall-rs");
+    let client = vcr_client();
     let client_card = client.card_named("Invalid Name!@#").await;
     let err = match client_card {
         Ok(_) => {
@@ -54,16 +65,14 @@ async fn test_card_named_invalid_name() {
         }
         Err(err) => err,
     };
-    assert_eq!(err.status, 404);
+    assert_eq!(err.status(), Some(404));
 }
 #[tokio::test]
 /// This test checks the encoding of the card name
 /// by requesting a card with a name that contains special characters.
 /// It expects a successful response from the Scryfall API.
 async fn test_card_named_special_characters() {
-    use scryfall_rs::ScryfallClient;
-
-    let mut client = ScryfallClient::new("scryfall-rs");
+    let client = vcr_client();
     let client_card = client.card_named("Y'shtola, Night's Blessed").await;
     let card = match client_card {
         Ok(card) => card,
@@ -77,9 +86,7 @@ async fn test_card_named_special_characters() {
 
 #[tokio::test]
 async fn test_api_inconsistency_card_back_id() {
-    use scryfall_rs::ScryfallClient;
-
-    let mut client = ScryfallClient::new("scryfall-rs");
+    let client = vcr_client();
     let client_card = client
         .card_named("Arlinn Kord // Arlinn, Embraced by the Moon")
         .await;
@@ -95,9 +102,7 @@ async fn test_api_inconsistency_card_back_id() {
 
 #[tokio::test]
 async fn test_api_inconsistency_type_line() {
-    use scryfall_rs::ScryfallClient;
-
-    let mut client = ScryfallClient::new("scryfall-rs");
+    let client = vcr_client();
     let client_card = client
         .card_named("Grimgrin, Corpse-Born // Grimgrin, Corpse-Born")
         .await;
@@ -110,3 +115,86 @@ async fn test_api_inconsistency_type_line() {
     };
     assert_eq!(card.name, "Grimgrin, Corpse-Born // Grimgrin, Corpse-Born");
 }
+
+#[tokio::test]
+/// This test checks the functionality of the `card_named_fuzzy` function
+/// by requesting a card with a partial, lowercase name.
+/// It expects a successful response from the Scryfall API.
+async fn test_card_named_fuzzy() {
+    let client = vcr_client();
+    let client_card = client.card_named_fuzzy("grimgrin corpse", None).await;
+    let card = match client_card {
+        Ok(card) => card,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to fetch card fuzzily named 'grimgrin corpse'");
+        }
+    };
+    assert_eq!(card.name, "Grimgrin, Corpse-Born");
+}
+
+#[tokio::test]
+/// This test checks the `set` restriction of the `card_named_fuzzy` function.
+/// It expects the returned printing to belong to the requested set.
+async fn test_card_named_fuzzy_set() {
+    let client = vcr_client();
+    let client_card = client.card_named_fuzzy("black lotus", Some("lea")).await;
+    let card = match client_card {
+        Ok(card) => card,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to fetch card fuzzily named 'black lotus' from LEA");
+        }
+    };
+    assert_eq!(card.name, "Black Lotus");
+    assert_eq!(card.set, "lea");
+}
+
+#[tokio::test]
+/// This test checks the error handling of the `card_named_fuzzy` function
+/// by requesting a name that matches many cards.
+/// It expects an ambiguous error from the Scryfall API.
+async fn test_card_named_fuzzy_ambiguous() {
+    use scryfall_rs::{CardNamedError, ErrorKind};
+
+    let client = vcr_client();
+    let client_card = client.card_named_fuzzy("jace", None).await;
+    match client_card {
+        Err(CardNamedError::Ambiguous(err)) => {
+            assert_eq!(err.status, 404);
+            assert_eq!(err.type_, Some(ErrorKind::Ambiguous));
+        }
+        Err(err) => panic!("Expected an ambiguous error, got: {}", err),
+        Ok(card) => panic!("Expected an ambiguous error, got card {}", card.name),
+    }
+}
+
+#[tokio::test]
+/// This test checks `card_named` against an in-process fixture transport
+/// behind a custom base URL, so it runs without network access.
+/// It expects the request path to keep the base URL's prefix.
+async fn test_card_named_fixture() {
+    use scryfall_rs::{FixtureTransport, ScryfallClient};
+
+    let body = std::fs::read("tests/data/black_lotus.json").expect("Failed to read test data");
+    let transport =
+        FixtureTransport::new().with_response("/scryfall/cards/named?exact=Black+Lotus", 200, body);
+    let client = ScryfallClient::builder("scryfall-rs")
+        .base_url("http://localhost:8080/scryfall".parse().unwrap())
+        .transport(transport)
+        .build();
+    let card = match client.card_named("Black Lotus").await {
+        Ok(card) => card,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to fetch card named 'Black Lotus'");
+        }
+    };
+    assert_eq!(card.name, "Black Lotus");
+
+    let err = match client.card_named("Nonexistent Card").await {
+        Ok(_) => panic!("Successfully fetched a card that has no fixture"),
+        Err(err) => err,
+    };
+    assert_eq!(err.status(), Some(404));
+}
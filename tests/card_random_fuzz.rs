@@ -1,63 +1,109 @@
+use futures::future::BoxFuture;
+use scryfall_rs::client::http::{HttpClient, HttpResponse};
+use scryfall_rs::{Card, ScryfallClient, ScryfallClientError, Transport};
+use std::sync::{Arc, Mutex};
+use url::Url;
+
+/// Filters for `test_card_random_queries`, one request each.
+const QUERIES: &[&str] = &[
+    "t:creature",
+    "t:instant",
+    "t:land",
+    "is:commander",
+    "r:mythic",
+    "c:g cmc<=2",
+    "o:draw",
+    "is:dfc",
+    "set:lea",
+    "-t:basic",
+];
+
+/// Sends requests over the network, keeping the last response body so a
+/// card that fails to decode can be dumped in full.
+#[derive(Clone)]
+struct LastBody {
+    inner: HttpClient,
+    body: Arc<Mutex<Vec<u8>>>,
+}
+
+impl LastBody {
+    fn new() -> Self {
+        LastBody {
+            inner: HttpClient::new("scryfall-rs"),
+            body: Arc::default(),
+        }
+    }
+
+    fn client(&self) -> ScryfallClient {
+        ScryfallClient::builder("scryfall-rs")
+            .transport(self.clone())
+            .build()
+    }
+
+    fn keep(
+        &self,
+        response: Result<HttpResponse, ScryfallClientError>,
+    ) -> Result<HttpResponse, ScryfallClientError> {
+        if let Ok(response) = &response {
+            *self.body.lock().unwrap() = response.body.clone();
+        }
+        response
+    }
+
+    /// Fetches a random card, dumping the raw JSON if it doesn't decode.
+    async fn card_random(&self, client: &ScryfallClient, query: Option<&str>) -> Card {
+        match client.card_random(query).await {
+            Ok(card) => card,
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                if let ScryfallClientError::Deserialize { .. } = err {
+                    let json_str = String::from_utf8_lossy(&self.body.lock().unwrap()).into_owned();
+                    dbg!(&json_str);
+                }
+                panic!("Failed to fetch a random card for {query:?}");
+            }
+        }
+    }
+}
+
+impl Transport for LastBody {
+    fn get<'a>(&'a self, url: &'a Url) -> BoxFuture<'a, Result<HttpResponse, ScryfallClientError>> {
+        Box::pin(async move { self.keep(self.inner.get(url).await) })
+    }
+
+    fn post_json<'a>(
+        &'a self,
+        url: &'a Url,
+        body: Vec<u8>,
+    ) -> BoxFuture<'a, Result<HttpResponse, ScryfallClientError>> {
+        Box::pin(async move { self.keep(self.inner.post_json(url, body).await) })
+    }
+}
+
 #[tokio::test]
+#[ignore = "hits api.scryfall.com"]
 /// This test checks the error handling of the `card_random` function
 /// by requesting a random card.
 /// It expects a successful response from the Scryfall API.
 async fn test_card_random() {
-    use reqwest::ClientBuilder;
-    use scryfall_rs::structs::Card;
-
-    let client = ClientBuilder::new()
-        .user_agent("scryfall-rs")
-        .build()
-        .expect("Failed to build client");
-    let url = "https://api.scryfall.com/cards/random";
-    let response = client
-        .get(url)
-        .send()
-        .await
-        .expect("Failed to send request");
-    let json_str = response.text().await.expect("Failed to read response");
-    let card: Card = serde_json::from_str(&json_str)
-        .map_err(|e| {
-            eprintln!("Failed to parse JSON: {}", e);
-            dbg!(&json_str);
-            panic!("Failed to parse JSON");
-        })
-        .expect("Failed to parse JSON");
-    assert!(card.name.len() > 0);
+    let transport = LastBody::new();
+    let client = transport.client();
+    let card = transport.card_random(&client, None).await;
+    assert!(!card.name.is_empty());
 }
 
 #[tokio::test]
+#[ignore = "hits api.scryfall.com"]
 /// This test checks the error handling of the `card_random` function
 /// by requesting multiple random cards.
 /// It expects a successful response from the Scryfall API.
 async fn test_card_random_multiple() {
-    use reqwest::ClientBuilder;
-    use scryfall_rs::structs::Card;
-
-    let client = ClientBuilder::new()
-        .user_agent("scryfall-rs")
-        .build()
-        .expect("Failed to build client");
-    let url = "https://api.scryfall.com/cards/random";
-
+    let transport = LastBody::new();
+    let client = transport.client();
     for iter in 0..20 {
         for _ in 0..10 {
-            let response = client
-                .get(url)
-                .send()
-                .await
-                .expect("Failed to send request");
-            let json_str = response.text().await.expect("Failed to read response");
-            let card: Card = serde_json::from_str(&json_str)
-                .map_err(|e| {
-                    eprintln!("Failed to parse JSON: {}", e);
-                    dbg!(&json_str);
-                    panic!("Failed to parse JSON");
-                })
-                .expect("Failed to parse JSON");
-            assert!(card.name.len() > 0);
-            std::thread::sleep(std::time::Duration::from_millis(50)); //ratelimiting
+            let card = transport.card_random(&client, None).await;
+            assert!(!card.name.is_empty());
         }
         println!("{} cards fetched", iter * 10 + 10);
     }
@@ -68,36 +114,92 @@ async fn test_card_random_multiple() {
 /// This test checks the error handling of the `card_random` function
 /// by requesting multiple random cards.
 /// It expects a successful response from the Scryfall API.
-/// Expected to take at minimum 8.3 minutes to run solely due to rate limiting.
+/// Expected to take at minimum 83 minutes to run solely due to rate limiting.
 /// However, network latency and other factors will increase this time.
 async fn test_card_random_multiple_10000() {
-    use reqwest::ClientBuilder;
-    use scryfall_rs::structs::Card;
-
-    let client = ClientBuilder::new()
-        .user_agent("scryfall-rs")
-        .build()
-        .expect("Failed to build client");
-    let url = "https://api.scryfall.com/cards/random";
-
+    let transport = LastBody::new();
+    let client = transport.client();
     for iter in 0..1000 {
         for _ in 0..10 {
-            let response = client
-                .get(url)
-                .send()
-                .await
-                .expect("Failed to send request");
-            let json_str = response.text().await.expect("Failed to read response");
-            let card: Card = serde_json::from_str(&json_str)
-                .map_err(|e| {
-                    eprintln!("Failed to parse JSON: {}", e);
-                    dbg!(&json_str);
-                    panic!("Failed to parse JSON");
-                })
-                .expect("Failed to parse JSON");
-            assert!(card.name.len() > 0);
-            std::thread::sleep(std::time::Duration::from_millis(50)); //ratelimiting
+            let card = transport.card_random(&client, None).await;
+            assert!(!card.name.is_empty());
         }
         println!("{} cards fetched", iter * 10 + 10);
     }
 }
+
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the error handling of the `card_random` function
+/// by requesting a random card for each of a range of filters.
+/// It expects a successful response from the Scryfall API.
+async fn test_card_random_queries() {
+    let transport = LastBody::new();
+    let client = transport.client();
+    for query in QUERIES {
+        let card = transport.card_random(&client, Some(query)).await;
+        assert!(!card.name.is_empty());
+    }
+}
+
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the query filter of the `card_random` function
+/// by requesting multiple random cards restricted to legendary commanders.
+/// It expects every card to satisfy the filter.
+async fn test_card_random_filtered() {
+    let client = ScryfallClient::new("scryfall-rs");
+    for _ in 0..20 {
+        let card = match client.card_random(Some("is:commander t:legendary")).await {
+            Ok(card) => card,
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                panic!("Failed to fetch a random commander");
+            }
+        };
+        assert!(!card.name.is_empty());
+        let type_line = card
+            .type_line
+            .or_else(|| card.card_faces.and_then(|faces| faces[0].type_line.clone()))
+            .unwrap_or_default();
+        assert!(type_line.contains("Legendary"));
+    }
+}
+
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the error handling of the `card_random` function
+/// by requesting a random card from a query that matches nothing.
+/// It expects a 404 error response from the Scryfall API.
+async fn test_card_random_filtered_no_match() {
+    let client = ScryfallClient::new("scryfall-rs");
+    let err = match client
+        .card_random(Some("t:goblin t:planeswalker o:zzzzzz"))
+        .await
+    {
+        Ok(_) => panic!("Successfully fetched a card for an empty query"),
+        Err(err) => err,
+    };
+    assert_eq!(err.status(), Some(404));
+}
+
+#[tokio::test]
+/// This test checks `card_random` against an in-process fixture transport,
+/// so it runs without network access.
+/// It expects the card served for `/cards/random`.
+async fn test_card_random_fixture() {
+    use scryfall_rs::FixtureTransport;
+
+    let body = std::fs::read("tests/data/black_lotus.json").expect("Failed to read test data");
+    let client = ScryfallClient::builder("scryfall-rs")
+        .transport(FixtureTransport::new().with_response("/cards/random", 200, body))
+        .build();
+    let card = match client.card_random(None).await {
+        Ok(card) => card,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to fetch a random card");
+        }
+    };
+    assert_eq!(card.name, "Black Lotus");
+}
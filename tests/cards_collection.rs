@@ -0,0 +1,107 @@
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the functionality of the `cards_collection` function
+/// by resolving a mix of identifier kinds in one call.
+/// It expects the found cards in input order and the missing one reported separately.
+async fn test_cards_collection() {
+    use scryfall_rs::{CardIdentifier, ScryfallClient, ScryfallID};
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let missing = CardIdentifier::Name {
+        name: "Nonexistent Card".to_string(),
+    };
+    let identifiers = vec![
+        CardIdentifier::Name {
+            name: "Black Lotus".to_string(),
+        },
+        missing.clone(),
+        CardIdentifier::Id {
+            id: ScryfallID("bd8fa327-dd41-4737-8f19-2cf5eb1f7cdd".parse().unwrap()),
+        },
+        CardIdentifier::SetCollectorNumber {
+            set: "m21".to_string(),
+            collector_number: "183".to_string(),
+        },
+        CardIdentifier::NameSet {
+            name: "Llanowar Elves".to_string(),
+            set: "dom".to_string(),
+        },
+    ];
+    let collection = match client.cards_collection(&identifiers).await {
+        Ok(collection) => collection,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to resolve card collection");
+        }
+    };
+    assert_eq!(collection.cards.len(), 4);
+    assert_eq!(collection.cards[0].name, "Black Lotus");
+    assert_eq!(collection.cards[1].set, "lea");
+    assert_eq!(collection.cards[2].set, "m21");
+    assert_eq!(collection.cards[3].name, "Llanowar Elves");
+    assert_eq!(collection.cards[3].set, "dom");
+    assert_eq!(collection.not_found, vec![missing]);
+}
+
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the batching of the `cards_collection` function
+/// by resolving more identifiers than Scryfall accepts in a single request.
+/// It expects every card back, in input order.
+async fn test_cards_collection_chunked() {
+    use scryfall_rs::{CardIdentifier, ScryfallClient};
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let identifiers: Vec<_> = (1..=160)
+        .map(|number| CardIdentifier::SetCollectorNumber {
+            set: "m21".to_string(),
+            collector_number: number.to_string(),
+        })
+        .collect();
+    let collection = match client.cards_collection(&identifiers).await {
+        Ok(collection) => collection,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to resolve card collection");
+        }
+    };
+    assert!(collection.not_found.is_empty());
+    let numbers: Vec<_> = collection
+        .cards
+        .iter()
+        .map(|card| card.collector_number.parse::<u32>().unwrap())
+        .collect();
+    assert_eq!(numbers, (1..=160).collect::<Vec<_>>());
+}
+
+#[tokio::test]
+/// This test checks that `cards_collection` keeps the warnings Scryfall
+/// sends with a successful response, using a fixture transport.
+/// It expects the warnings next to the found cards.
+async fn test_cards_collection_warnings() {
+    use scryfall_rs::{CardIdentifier, FixtureTransport, ScryfallClient};
+
+    let card =
+        std::fs::read_to_string("tests/data/black_lotus.json").expect("Failed to read test data");
+    let body = format!(
+        r#"{{"object":"list","has_more":false,"data":[{card}],"not_found":[],"warnings":["Your request included unrecognized identifier fields."]}}"#
+    );
+    let client = ScryfallClient::builder("scryfall-rs")
+        .transport(FixtureTransport::new().with_response("/cards/collection", 200, body))
+        .build();
+    let identifiers = vec![CardIdentifier::Name {
+        name: "Black Lotus".to_string(),
+    }];
+    let collection = match client.cards_collection(&identifiers).await {
+        Ok(collection) => collection,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to resolve card collection");
+        }
+    };
+    assert_eq!(collection.cards.len(), 1);
+    assert_eq!(
+        collection.warnings,
+        ["Your request included unrecognized identifier fields."]
+    );
+}
@@ -0,0 +1,62 @@
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the functionality of the `catalog` function
+/// by requesting the creature type catalog.
+/// It expects common creature types to be present.
+async fn test_catalog_creature_types() {
+    use scryfall_rs::{CatalogKind, ScryfallClient};
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let catalog = match client.catalog(CatalogKind::CreatureTypes).await {
+        Ok(catalog) => catalog,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to fetch the creature type catalog");
+        }
+    };
+    assert_eq!(catalog.total_values, catalog.data.len());
+    assert!(catalog.data.contains(&"Goblin".to_string()));
+    assert!(catalog.data.contains(&"Zombie".to_string()));
+}
+
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the `catalog` function against every catalog kind.
+/// It expects each catalog to be non-empty.
+async fn test_catalog_all_kinds() {
+    use scryfall_rs::{CatalogKind, ScryfallClient};
+
+    let kinds = [
+        CatalogKind::CardNames,
+        CatalogKind::ArtistNames,
+        CatalogKind::WordBank,
+        CatalogKind::Supertypes,
+        CatalogKind::CardTypes,
+        CatalogKind::ArtifactTypes,
+        CatalogKind::BattleTypes,
+        CatalogKind::CreatureTypes,
+        CatalogKind::EnchantmentTypes,
+        CatalogKind::LandTypes,
+        CatalogKind::PlaneswalkerTypes,
+        CatalogKind::SpellTypes,
+        CatalogKind::Powers,
+        CatalogKind::Toughnesses,
+        CatalogKind::Loyalties,
+        CatalogKind::FlavorWords,
+        CatalogKind::KeywordAbilities,
+        CatalogKind::KeywordActions,
+        CatalogKind::AbilityWords,
+        CatalogKind::Watermarks,
+    ];
+    let client = ScryfallClient::new("scryfall-rs");
+    for kind in kinds {
+        let catalog = match client.catalog(kind).await {
+            Ok(catalog) => catalog,
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                panic!("Failed to fetch the {} catalog", kind.path());
+            }
+        };
+        assert!(!catalog.data.is_empty(), "{} is empty", kind.path());
+    }
+}
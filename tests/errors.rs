@@ -0,0 +1,163 @@
+fn fixture_client(
+    path_and_query: &str,
+    status: u16,
+    body: impl Into<Vec<u8>>,
+) -> scryfall_rs::ScryfallClient {
+    use scryfall_rs::{FixtureTransport, RateLimit, RetryPolicy, ScryfallClient};
+    use std::time::Duration;
+
+    ScryfallClient::builder("scryfall-rs")
+        .transport(FixtureTransport::new().with_response(path_and_query, status, body))
+        .rate_limit(RateLimit::new(Duration::ZERO))
+        .retry(RetryPolicy::none())
+        .build()
+}
+
+#[tokio::test]
+/// This test checks the error reported for a card whose fields don't
+/// match this crate's types.
+/// It expects a decoding error naming the offending field and its value.
+async fn test_deserialize_error_path() {
+    use scryfall_rs::ScryfallClientError;
+
+    let body = std::fs::read_to_string("tests/data/black_lotus.json")
+        .expect("Failed to read test data")
+        .replacen(r#""cmc": 0.0"#, r#""cmc": "zero""#, 1);
+    assert!(body.contains(r#""cmc": "zero""#));
+    let client = fixture_client("/cards/named?exact=Black+Lotus", 200, body);
+    match client.card_named("Black Lotus").await {
+        Err(ScryfallClientError::Deserialize { path, snippet, .. }) => {
+            assert_eq!(path.to_string(), "cmc");
+            assert_eq!(snippet, r#""zero""#);
+        }
+        Err(err) => panic!("Expected a decoding error, got: {:?}", err),
+        Ok(card) => panic!("Expected a decoding error, got card {}", card.name),
+    }
+}
+
+#[tokio::test]
+/// This test checks the error reported for a bad element of a card list.
+/// It expects the path to lead from the list through the element to the field.
+async fn test_deserialize_error_list_path() {
+    use futures::StreamExt;
+    use scryfall_rs::{JsonPath, PathSegment, ScryfallClientError};
+
+    let card =
+        std::fs::read_to_string("tests/data/black_lotus.json").expect("Failed to read test data");
+    let broken = card.replacen(r#""cmc": 0.0"#, r#""cmc": "zero""#, 1);
+    let body = format!(r#"{{"object":"list","has_more":false,"data":[{card},{broken}]}}"#);
+    let client = fixture_client("/cards/search?q=lotus", 200, body);
+    let url = "https://api.scryfall.com/cards/search?q=lotus"
+        .parse()
+        .unwrap();
+    let results: Vec<_> = client.card_list_stream(url).collect().await;
+    match &results[..] {
+        [Err(ScryfallClientError::Deserialize { path, snippet, .. })] => {
+            assert_eq!(path.to_string(), "data[1].cmc");
+            assert_eq!(
+                *path,
+                JsonPath(vec![
+                    PathSegment::Field("data".to_string()),
+                    PathSegment::Index(1),
+                    PathSegment::Field("cmc".to_string()),
+                ])
+            );
+            assert_eq!(snippet, r#""zero""#);
+        }
+        results => panic!("Expected a single decoding error, got: {:?}", results),
+    }
+}
+
+#[tokio::test]
+/// This test checks the error reported for an unsuccessful response
+/// that isn't a Scryfall error object, such as a proxy's error page.
+/// It expects an HTTP error carrying the status and the start of the body.
+async fn test_http_error() {
+    use scryfall_rs::ScryfallClientError;
+
+    let client = fixture_client(
+        "/cards/named?exact=Black+Lotus",
+        502,
+        "<html>Bad Gateway</html>",
+    );
+    let err = match client.card_named("Black Lotus").await {
+        Ok(card) => panic!("Expected an HTTP error, got card {}", card.name),
+        Err(err) => err,
+    };
+    assert_eq!(err.status(), Some(502));
+    assert!(err.api_error().is_none());
+    match err {
+        ScryfallClientError::Http { snippet, .. } => {
+            assert_eq!(snippet, "<html>Bad Gateway</html>")
+        }
+        err => panic!("Expected an HTTP error, got: {:?}", err),
+    }
+}
+
+#[tokio::test]
+/// This test checks the error reported for a Scryfall error object.
+/// It expects an API error exposing the object's fields.
+async fn test_api_error() {
+    use scryfall_rs::ScryfallClientError;
+
+    let body = r#"{"object":"error","code":"not_found","status":404,"details":"No cards found matching “Nonexistent Card”"}"#;
+    let client = fixture_client("/cards/named?exact=Nonexistent+Card", 404, body);
+    let err = match client.card_named("Nonexistent Card").await {
+        Ok(card) => panic!("Expected an API error, got card {}", card.name),
+        Err(err) => err,
+    };
+    assert!(matches!(err, ScryfallClientError::Api(_)));
+    assert_eq!(err.status(), Some(404));
+    assert_eq!(err.api_error().unwrap().code, "not_found");
+}
+
+#[test]
+/// This test checks that error types Scryfall adds in the future still deserialize.
+/// It expects unrecognised values to round-trip through `ErrorKind::Unknown`.
+fn test_error_kind() {
+    use scryfall_rs::{ErrorKind, ScryfallError};
+
+    let err: ScryfallError = serde_json::from_str(
+        r#"{"object":"error","code":"not_found","type":"ambiguous","status":404,"details":"Too many cards match ambiguous name “jace”."}"#,
+    )
+    .unwrap();
+    assert_eq!(err.type_, Some(ErrorKind::Ambiguous));
+    let kind: ErrorKind = serde_json::from_str("\"mystery\"").unwrap();
+    assert_eq!(kind, ErrorKind::Unknown("mystery".to_string()));
+    assert_eq!(serde_json::to_string(&kind).unwrap(), "\"mystery\"");
+}
+
+#[tokio::test]
+/// This test checks the attempt count of an error that isn't a Scryfall
+/// error object, by answering every retry with a 503 HTML page.
+/// It expects an HTTP error that remembers how many requests were sent.
+async fn test_http_error_attempts() {
+    use scryfall_rs::{
+        FixtureTransport, RateLimit, RetryPolicy, ScryfallClient, ScryfallClientError,
+    };
+    use std::time::Duration;
+
+    let transport = FixtureTransport::new().with_response(
+        "/cards/named?exact=Black+Lotus",
+        503,
+        "<html>Service Unavailable</html>",
+    );
+    let client = ScryfallClient::builder("scryfall-rs")
+        .transport(transport)
+        .rate_limit(RateLimit::new(Duration::ZERO))
+        .retry(RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(1),
+        })
+        .build();
+    let err = match client.card_named("Black Lotus").await {
+        Ok(card) => panic!("Expected an HTTP error, got card {}", card.name),
+        Err(err) => err,
+    };
+    assert!(
+        matches!(err, ScryfallClientError::Http { status: 503, .. }),
+        "{err:?}"
+    );
+    assert_eq!(err.attempts(), Some(3));
+}
@@ -0,0 +1,74 @@
+fn load_db() -> scryfall_rs::LocalDb {
+    let file = std::fs::File::open("tests/data/black_lotus_printings.json")
+        .expect("Failed to open test data");
+    scryfall_rs::LocalDb::from_reader(std::io::BufReader::new(file))
+        .expect("Failed to build local database")
+}
+
+#[test]
+/// This test checks the indexes of `LocalDb`
+/// by resolving each printing of a card through every kind of identifier.
+/// It expects the matching printing for every lookup.
+fn test_local_db_lookups() {
+    use scryfall_rs::{OracleID, ScryfallID};
+
+    let db = load_db();
+    assert_eq!(db.len(), 2);
+
+    let lea = ScryfallID("bd8fa327-dd41-4737-8f19-2cf5eb1f7cdd".parse().unwrap());
+    assert_eq!(db.card_by_id(&lea).unwrap().set, "lea");
+    assert_eq!(
+        db.card_by_set_number("LEB", "233", None).unwrap().set,
+        "leb"
+    );
+    assert_eq!(db.card_by_multiverse_id(3).unwrap().id, lea);
+    assert_eq!(db.card_by_tcgplayer_id(1105).unwrap().set, "leb");
+    assert_eq!(db.card_by_cardmarket_id(6666).unwrap().id, lea);
+
+    let oracle_id = OracleID("5089ec1a-f881-4d55-af14-5d996171203b".parse().unwrap());
+    assert_eq!(db.cards_by_oracle_id(&oracle_id).len(), 2);
+}
+
+#[test]
+/// This test checks the name index of `LocalDb`.
+/// It expects a case-insensitive match that prefers the newest printing.
+fn test_local_db_card_named() {
+    let db = load_db();
+    let card = db.card_named("black LOTUS").unwrap();
+    assert_eq!(card.name, "Black Lotus");
+    assert_eq!(card.set, "leb");
+}
+
+#[test]
+/// This test checks the error handling of `LocalDb`
+/// by looking up cards that are not in the database.
+/// It expects the same 404 error the Scryfall API would return.
+fn test_local_db_not_found() {
+    use scryfall_rs::Language;
+
+    let db = load_db();
+    assert_eq!(
+        db.card_named("Nonexistent Card").unwrap_err().status(),
+        Some(404)
+    );
+    assert_eq!(db.card_by_arena_id(1).unwrap_err().status(), Some(404));
+    let err = db
+        .card_by_set_number("lea", "232", Some(&Language::Japanese))
+        .unwrap_err();
+    assert_eq!(err.api_error().unwrap().code, "not_found");
+}
+
+#[tokio::test]
+/// This test checks that `LocalDb` can stand in for `ScryfallClient`
+/// through the `CardLookup` trait.
+async fn test_local_db_card_lookup() {
+    use scryfall_rs::{Card, CardLookup, ScryfallClientError};
+
+    async fn lookup(source: &impl CardLookup) -> Result<Card, ScryfallClientError> {
+        source.card_by_set_number("lea", "232", None).await
+    }
+
+    let db = load_db();
+    let card = lookup(&db).await.unwrap();
+    assert_eq!(card.name, "Black Lotus");
+}
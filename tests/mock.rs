@@ -0,0 +1,163 @@
+fn black_lotus_printings() -> Vec<scryfall_rs::Card> {
+    let file = std::fs::File::open("tests/data/black_lotus_printings.json")
+        .expect("Failed to open test data");
+    serde_json::from_reader(std::io::BufReader::new(file)).expect("Failed to parse test data")
+}
+
+#[tokio::test]
+/// This test checks the card endpoints of `MockServer`
+/// by looking Black Lotus up in every supported way.
+/// It expects the seeded printings back.
+async fn test_mock_card_lookups() {
+    use scryfall_rs::{MockServer, ScryfallID};
+
+    let server = MockServer::start(black_lotus_printings()).expect("Failed to start mock server");
+    let client = server.client();
+
+    let card = match client.card_named("black lotus").await {
+        Ok(card) => card,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to fetch card named 'Black Lotus'");
+        }
+    };
+    assert_eq!(card.set, "leb");
+
+    let lea = ScryfallID("bd8fa327-dd41-4737-8f19-2cf5eb1f7cdd".parse().unwrap());
+    assert_eq!(client.card_by_id(&lea).await.unwrap().set, "lea");
+    assert_eq!(
+        client
+            .card_by_set_number("lea", "232", None)
+            .await
+            .unwrap()
+            .id,
+        lea
+    );
+    assert_eq!(client.card_by_multiverse_id(3).await.unwrap().id, lea);
+    assert_eq!(
+        client
+            .card_named_fuzzy("lotus", Some("lea"))
+            .await
+            .unwrap()
+            .id,
+        lea
+    );
+    assert_eq!(client.card_random(None).await.unwrap().name, "Black Lotus");
+
+    let err = match client.card_named("Nonexistent Card").await {
+        Ok(_) => panic!("Successfully fetched a card that does not exist"),
+        Err(err) => err,
+    };
+    assert_eq!(err.status(), Some(404));
+    assert_eq!(server.request_count(), 7);
+}
+
+#[tokio::test]
+/// This test checks the search, collection and filtered random endpoints of
+/// `MockServer` by seeding more cards than fit on one search page.
+/// It expects every page to be followed, unknown identifiers reported and
+/// random cards to match their filter.
+async fn test_mock_search_and_collection() {
+    use scryfall_rs::{CardIdentifier, MockServer, ScryfallID, SearchOptions};
+
+    let lotus = black_lotus_printings().remove(0);
+    let mut cards = Vec::new();
+    for i in 0..200 {
+        let mut card = lotus.clone();
+        card.id = ScryfallID(uuid::Uuid::from_u128(i));
+        card.name = format!("Lotus Petal {i}");
+        card.collector_number = i.to_string();
+        cards.push(card);
+    }
+    let server = MockServer::start(cards).expect("Failed to start mock server");
+    let client = server.client();
+
+    let results = match client
+        .search("lotus petal", &SearchOptions::default())
+        .await
+    {
+        Ok(results) => results,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to search for 'lotus petal'");
+        }
+    };
+    assert_eq!(results.total_cards, 200);
+    assert_eq!(results.cards.len(), 200);
+    assert_eq!(server.request_count(), 2);
+
+    let identifiers = [
+        CardIdentifier::Name {
+            name: "Lotus Petal 7".to_string(),
+        },
+        CardIdentifier::SetCollectorNumber {
+            set: "lea".to_string(),
+            collector_number: "42".to_string(),
+        },
+        CardIdentifier::Name {
+            name: "Mox Pearl".to_string(),
+        },
+    ];
+    let collection = client.cards_collection(&identifiers).await.unwrap();
+    assert_eq!(collection.cards.len(), 2);
+    assert_eq!(collection.cards[1].name, "Lotus Petal 42");
+    assert_eq!(collection.not_found, vec![identifiers[2].clone()]);
+
+    // every word must appear, so only 19, 119 and 190 to 199 match
+    for _ in 0..10 {
+        let card = client.card_random(Some("petal 19")).await.unwrap();
+        assert!(card.name.contains("19"), "{}", card.name);
+    }
+    let err = client.card_random(Some("mox")).await.unwrap_err();
+    assert_eq!(err.status(), Some(404));
+}
+
+#[tokio::test]
+/// This test checks the fault switches of `MockServer`.
+/// It expects each injected fault to surface as the matching error.
+async fn test_mock_faults() {
+    use scryfall_rs::{CardNamedError, MockFault, MockServer, RetryPolicy, ScryfallClientError};
+    use std::time::Duration;
+
+    let server = MockServer::start(black_lotus_printings()).expect("Failed to start mock server");
+    let client = server.client();
+
+    server.fail_next(MockFault::NotFound);
+    assert_eq!(
+        client.card_named("Black Lotus").await.unwrap_err().status(),
+        Some(404)
+    );
+
+    server.fail_next(MockFault::Ambiguous);
+    match client.card_named_fuzzy("Black Lotus", None).await {
+        Err(CardNamedError::Ambiguous(err)) => assert_eq!(err.status, 404),
+        Err(err) => panic!("Expected an ambiguous error, got: {}", err),
+        Ok(card) => panic!("Expected an ambiguous error, got card {}", card.name),
+    }
+
+    server.fail_next(MockFault::MalformedJson);
+    let err = client.card_named("Black Lotus").await.unwrap_err();
+    assert!(
+        matches!(err, ScryfallClientError::Deserialize { .. }),
+        "{err:?}"
+    );
+
+    server.fail_always(Some(MockFault::RateLimited { retry_after: 0 }));
+    let err = client.card_named("Black Lotus").await.unwrap_err();
+    assert_eq!(err.status(), Some(429));
+    server.fail_always(None);
+
+    // a retrying client gets past a burst of rate limit errors
+    let mut client = server.client();
+    client.retry = RetryPolicy {
+        max_attempts: 3,
+        base_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(1),
+    };
+    server.fail_next(MockFault::RateLimited { retry_after: 0 });
+    server.fail_next(MockFault::RateLimited { retry_after: 0 });
+    assert_eq!(
+        client.card_named("Black Lotus").await.unwrap().name,
+        "Black Lotus"
+    );
+}
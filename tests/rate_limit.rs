@@ -0,0 +1,119 @@
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+/// This test checks that `RateLimit` serializes concurrent callers
+/// by sharing one limiter between 32 tasks.
+/// It expects the `n`th request to wait at least `n * limit`, however late
+/// the tasks before it woke up.
+async fn test_rate_limit_concurrent() {
+    use scryfall_rs::{EndpointClass, RateLimit};
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    let limit = Duration::from_millis(20);
+    let rate_limit = Arc::new(RateLimit::new(limit));
+    let start = Instant::now();
+    let tasks: Vec<_> = (0..32)
+        .map(|_| {
+            let rate_limit = rate_limit.clone();
+            tokio::spawn(async move {
+                rate_limit.check(EndpointClass::General).await;
+                Instant::now()
+            })
+        })
+        .collect();
+    let mut sent = Vec::new();
+    for task in tasks {
+        sent.push(task.await.expect("rate limited task panicked"));
+    }
+    sent.sort();
+    for (n, sent) in sent.iter().enumerate() {
+        // allow for timer granularity
+        assert!(*sent - start + Duration::from_millis(2) >= limit * n as u32);
+    }
+}
+
+#[test]
+/// This test checks that a client can be shared between threads and tasks.
+fn test_client_is_send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<scryfall_rs::ScryfallClient>();
+    assert_send_sync::<scryfall_rs::RateLimit>();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks that many tasks can share one client without a mutex.
+/// It expects every lookup to succeed.
+async fn test_shared_client() {
+    use scryfall_rs::ScryfallClient;
+    use std::sync::Arc;
+
+    let client = Arc::new(ScryfallClient::new("scryfall-rs"));
+    let tasks: Vec<_> = (0..32)
+        .map(|_| {
+            let client = client.clone();
+            tokio::spawn(async move { client.card_named("Black Lotus").await })
+        })
+        .collect();
+    for task in tasks {
+        let card = match task.await.expect("lookup task panicked") {
+            Ok(card) => card,
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                panic!("Failed to fetch card named 'Black Lotus'");
+            }
+        };
+        assert_eq!(card.name, "Black Lotus");
+    }
+}
+
+#[tokio::test]
+/// This test checks that heavy endpoints get their own, slower bucket.
+/// It expects consecutive searches to wait for the search limit while a
+/// general request right after only waits for the general limit.
+async fn test_rate_limit_buckets() {
+    use scryfall_rs::{EndpointClass, RateLimit};
+    use std::time::{Duration, Instant};
+
+    let rate_limit = RateLimit::new(Duration::from_millis(10))
+        .with_limit(EndpointClass::Search, Duration::from_millis(100));
+    let start = Instant::now();
+    rate_limit.check(EndpointClass::Search).await;
+    rate_limit.check(EndpointClass::General).await;
+    assert!(start.elapsed() < Duration::from_millis(80));
+    rate_limit.check(EndpointClass::Search).await;
+    assert!(start.elapsed() >= Duration::from_millis(98));
+}
+
+#[test]
+/// This test checks that request URLs are sorted into the right bucket,
+/// including later pages of a search.
+fn test_endpoint_class() {
+    use scryfall_rs::EndpointClass;
+    use url::Url;
+
+    let class = |url: &str| EndpointClass::of(&Url::parse(url).unwrap());
+    assert_eq!(
+        class("https://api.scryfall.com/cards/search?q=c%3Ared&page=2"),
+        EndpointClass::Search
+    );
+    assert_eq!(
+        class("https://api.scryfall.com/cards/named?exact=Black+Lotus"),
+        EndpointClass::Named
+    );
+    assert_eq!(
+        class("https://api.scryfall.com/cards/random"),
+        EndpointClass::Random
+    );
+    assert_eq!(
+        class("https://api.scryfall.com/cards/collection"),
+        EndpointClass::Collection
+    );
+    assert_eq!(
+        class("https://api.scryfall.com/cards/lea/232"),
+        EndpointClass::General
+    );
+    assert_eq!(
+        class("https://api.scryfall.com/sets"),
+        EndpointClass::General
+    );
+}
@@ -0,0 +1,257 @@
+use futures::future::BoxFuture;
+use scryfall_rs::client::http::HttpResponse;
+use scryfall_rs::{ScryfallClientError, Transport};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use url::Url;
+
+/// Answers requests with a fixed sequence of responses and counts them.
+#[derive(Clone, Default)]
+struct Scripted {
+    responses: Arc<Mutex<VecDeque<HttpResponse>>>,
+    requests: Arc<Mutex<u32>>,
+}
+
+impl Scripted {
+    fn new<'a>(responses: impl IntoIterator<Item = (u16, &'a str, Option<Duration>)>) -> Self {
+        let responses = responses
+            .into_iter()
+            .map(|(status, body, retry_after)| HttpResponse {
+                status,
+                body: body.as_bytes().to_vec(),
+                retry_after,
+            })
+            .collect();
+        Scripted {
+            responses: Arc::new(Mutex::new(responses)),
+            ..Self::default()
+        }
+    }
+
+    fn requests(&self) -> u32 {
+        *self.requests.lock().unwrap()
+    }
+
+    fn respond(&self) -> Result<HttpResponse, ScryfallClientError> {
+        *self.requests.lock().unwrap() += 1;
+        Ok(self
+            .responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .expect("More requests than scripted responses"))
+    }
+
+    fn client(&self, retry: scryfall_rs::RetryPolicy) -> scryfall_rs::ScryfallClient {
+        use scryfall_rs::{RateLimit, ScryfallClient};
+
+        ScryfallClient::builder("scryfall-rs")
+            .transport(self.clone())
+            .rate_limit(RateLimit::new(Duration::ZERO))
+            .retry(retry)
+            .build()
+    }
+}
+
+impl Transport for Scripted {
+    fn get<'a>(
+        &'a self,
+        _url: &'a Url,
+    ) -> BoxFuture<'a, Result<HttpResponse, ScryfallClientError>> {
+        Box::pin(async move { self.respond() })
+    }
+
+    fn post_json<'a>(
+        &'a self,
+        _url: &'a Url,
+        _body: Vec<u8>,
+    ) -> BoxFuture<'a, Result<HttpResponse, ScryfallClientError>> {
+        Box::pin(async move { self.respond() })
+    }
+}
+
+const RATE_LIMITED: &str =
+    r#"{"object":"error","code":"rate_limited","status":429,"details":"Too many requests"}"#;
+
+#[test]
+/// This test checks which statuses the retry policy retries.
+/// It expects 429 and 5xx to be retried and everything else not.
+fn test_should_retry() {
+    use scryfall_rs::RetryPolicy;
+
+    assert!(RetryPolicy::should_retry(429));
+    assert!(RetryPolicy::should_retry(500));
+    assert!(RetryPolicy::should_retry(503));
+    assert!(!RetryPolicy::should_retry(200));
+    assert!(!RetryPolicy::should_retry(400));
+    assert!(!RetryPolicy::should_retry(404));
+}
+
+#[test]
+/// This test checks the backoff between retries.
+/// It expects each delay to double, stay capped at `max_delay`, and lose at
+/// most half of itself to jitter.
+fn test_retry_delay() {
+    use scryfall_rs::RetryPolicy;
+    use std::time::Duration;
+
+    let policy = RetryPolicy {
+        max_attempts: 10,
+        base_delay: Duration::from_millis(100),
+        max_delay: Duration::from_millis(1000),
+    };
+    for (attempt, expected) in [(1, 100), (2, 200), (3, 400), (4, 800), (5, 1000), (9, 1000)] {
+        let expected = Duration::from_millis(expected);
+        for _ in 0..20 {
+            let delay = policy.delay(attempt, None);
+            assert!(
+                delay <= expected,
+                "attempt {attempt}: {delay:?} > {expected:?}"
+            );
+            assert!(
+                delay >= expected / 2,
+                "attempt {attempt}: {delay:?} < {expected:?} / 2"
+            );
+        }
+    }
+}
+
+#[test]
+/// This test checks that a `Retry-After` header overrides the backoff.
+fn test_retry_after() {
+    use scryfall_rs::RetryPolicy;
+    use std::time::Duration;
+
+    let policy = RetryPolicy::default();
+    assert_eq!(
+        policy.delay(1, Some(Duration::from_secs(3))),
+        Duration::from_secs(3)
+    );
+    assert_eq!(
+        policy.delay(1, Some(Duration::from_secs(86400))),
+        Duration::from_secs(86400)
+    );
+    assert!(policy.honors(None));
+    assert!(policy.honors(Some(policy.max_delay)));
+    assert!(!policy.honors(Some(Duration::from_secs(86400))));
+    assert_eq!(RetryPolicy::none().max_attempts, 1);
+}
+
+#[tokio::test]
+/// This test checks that `ScryfallClient` retries GETs that fail with a
+/// 429 or 5xx status, waiting as long as `Retry-After` asks.
+/// It expects the card from the final attempt.
+async fn test_client_retries() {
+    use scryfall_rs::RetryPolicy;
+    use std::time::Instant;
+
+    let card =
+        std::fs::read_to_string("tests/data/black_lotus.json").expect("Failed to read test data");
+    let transport = Scripted::new([
+        (429, RATE_LIMITED, Some(Duration::from_millis(300))),
+        (503, "<html>Service Unavailable</html>", None),
+        (200, &card, None),
+    ]);
+    let client = transport.client(RetryPolicy {
+        max_attempts: 3,
+        base_delay: Duration::from_millis(1),
+        max_delay: Duration::from_secs(1),
+    });
+    let start = Instant::now();
+    let card = match client.card_named("Black Lotus").await {
+        Ok(card) => card,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to fetch card named 'Black Lotus' after retrying");
+        }
+    };
+    assert_eq!(card.name, "Black Lotus");
+    assert_eq!(transport.requests(), 3);
+    assert!(start.elapsed() >= Duration::from_millis(300));
+}
+
+#[tokio::test]
+/// This test checks that `ScryfallClient` gives up once `max_attempts`
+/// requests have failed.
+/// It expects the last rate limit error, with the number of attempts.
+async fn test_client_retries_exhausted() {
+    use scryfall_rs::RetryPolicy;
+
+    let transport = Scripted::new([(429, RATE_LIMITED, None), (429, RATE_LIMITED, None)]);
+    let client = transport.client(RetryPolicy {
+        max_attempts: 2,
+        base_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(1),
+    });
+    let err = match client.card_named("Black Lotus").await {
+        Ok(card) => panic!("Expected a rate limit error, got card {}", card.name),
+        Err(err) => err,
+    };
+    assert!(
+        matches!(err, ScryfallClientError::RateLimited { .. }),
+        "{err:?}"
+    );
+    assert_eq!(err.api_error().unwrap().attempts, Some(2));
+    assert_eq!(transport.requests(), 2);
+}
+
+#[tokio::test]
+/// This test checks that `ScryfallClient` doesn't retry early when
+/// `Retry-After` asks for longer than `max_delay`.
+/// It expects the rate limit error right away, with the requested delay
+/// and the number of attempts.
+async fn test_client_retry_after_too_long() {
+    use scryfall_rs::RetryPolicy;
+    use std::time::Instant;
+
+    let transport = Scripted::new([
+        (429, RATE_LIMITED, Some(Duration::from_secs(60))),
+        (429, RATE_LIMITED, None),
+    ]);
+    let client = transport.client(RetryPolicy {
+        max_attempts: 3,
+        base_delay: Duration::from_millis(1),
+        max_delay: Duration::from_secs(1),
+    });
+    let start = Instant::now();
+    let err = match client.card_named("Black Lotus").await {
+        Ok(card) => panic!("Expected a rate limit error, got card {}", card.name),
+        Err(err) => err,
+    };
+    match &err {
+        ScryfallClientError::RateLimited { retry_after, .. } => {
+            assert_eq!(*retry_after, Some(Duration::from_secs(60)))
+        }
+        err => panic!("Expected a rate limit error, got: {:?}", err),
+    }
+    assert_eq!(err.attempts(), Some(1));
+    assert_eq!(transport.requests(), 1);
+    assert!(start.elapsed() < Duration::from_secs(1));
+}
+
+#[tokio::test]
+/// This test checks that `ScryfallClient` never retries a POST.
+/// It expects the first 503 back after a single request.
+async fn test_client_does_not_retry_post() {
+    use scryfall_rs::{CardIdentifier, RetryPolicy};
+
+    let transport = Scripted::new([
+        (503, "<html>Service Unavailable</html>", None),
+        (503, "<html>Service Unavailable</html>", None),
+    ]);
+    let client = transport.client(RetryPolicy {
+        max_attempts: 2,
+        base_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(1),
+    });
+    let identifiers = vec![CardIdentifier::Name {
+        name: "Black Lotus".to_string(),
+    }];
+    let err = match client.cards_collection(&identifiers).await {
+        Ok(_) => panic!("Expected an HTTP error"),
+        Err(err) => err,
+    };
+    assert_eq!(err.status(), Some(503));
+    assert_eq!(transport.requests(), 1);
+}
@@ -0,0 +1,70 @@
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the functionality of `Card::rulings`
+/// by following the rulings URI of a card with known rulings.
+/// It expects at least one ruling for the card's oracle ID.
+async fn test_card_rulings() {
+    use scryfall_rs::ScryfallClient;
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let card = client
+        .card_named("Grimgrin, Corpse-Born")
+        .await
+        .expect("Failed to fetch Grimgrin, Corpse-Born");
+    let rulings = match card.rulings(&client).await {
+        Ok(rulings) => rulings,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to fetch rulings for Grimgrin, Corpse-Born");
+        }
+    };
+    assert!(!rulings.is_empty());
+    assert!(
+        rulings
+            .iter()
+            .all(|ruling| Some(&ruling.oracle_id) == card.oracle_id.as_ref())
+    );
+}
+
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks every `rulings_by_*` function
+/// by resolving the rulings of one card through each of its identifiers.
+/// It expects the same rulings every time.
+async fn test_rulings_lookups() {
+    use scryfall_rs::ScryfallClient;
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let card = client
+        .card_by_set_number("m21", "183", None)
+        .await
+        .expect("Failed to fetch M21 #183");
+    let expected = client
+        .rulings_by_id(&card.id)
+        .await
+        .expect("Failed to fetch rulings by id");
+
+    let by_set_number = client
+        .rulings_by_set_number("m21", "183")
+        .await
+        .expect("Failed to fetch rulings by set and collector number");
+    assert_eq!(by_set_number, expected);
+
+    let by_multiverse = client
+        .rulings_by_multiverse_id(card.multiverse_ids[0])
+        .await
+        .expect("Failed to fetch rulings by multiverse id");
+    assert_eq!(by_multiverse, expected);
+
+    let by_mtgo = client
+        .rulings_by_mtgo_id(card.mtgo_id.expect("M21 printing has an MTGO id"))
+        .await
+        .expect("Failed to fetch rulings by mtgo id");
+    assert_eq!(by_mtgo, expected);
+
+    let by_arena = client
+        .rulings_by_arena_id(card.arena_id.expect("M21 printing has an Arena id"))
+        .await
+        .expect("Failed to fetch rulings by arena id");
+    assert_eq!(by_arena, expected);
+}
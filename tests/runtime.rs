@@ -0,0 +1,99 @@
+#[cfg(not(feature = "tokio"))]
+#[test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks that the client works without a tokio runtime
+/// by looking a card up from inside smol's executor.
+/// It expects a successful response from the Scryfall API.
+fn test_card_named_smol() {
+    use scryfall_rs::ScryfallClient;
+
+    smol::block_on(async {
+        let client = ScryfallClient::new("scryfall-rs");
+        let card = match client.card_named("Black Lotus").await {
+            Ok(card) => card,
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                panic!("Failed to fetch card named 'Black Lotus'");
+            }
+        };
+        assert_eq!(card.name, "Black Lotus");
+    });
+}
+
+#[cfg(not(feature = "tokio"))]
+#[test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks that rate limiting waits asynchronously
+/// by running lookups next to a ticking task on a single-threaded executor.
+/// It expects the ticking task to make progress while the client waits.
+fn test_rate_limit_does_not_block_executor() {
+    use scryfall_rs::ScryfallClient;
+    use std::cell::Cell;
+
+    let ticks = Cell::new(0);
+    let ex = smol::LocalExecutor::new();
+    smol::block_on(ex.run(async {
+        let ticker = ex.spawn(async {
+            loop {
+                ticks.set(ticks.get() + 1);
+                smol::Timer::after(std::time::Duration::from_millis(5)).await;
+            }
+        });
+        let client = ScryfallClient::new("scryfall-rs");
+        for _ in 0..3 {
+            let _ = client.card_named("Black Lotus").await;
+        }
+        drop(ticker);
+    }));
+    assert!(ticks.get() > 3);
+}
+
+#[cfg(all(not(feature = "tokio"), feature = "mock"))]
+#[test]
+/// This test checks the `ureq` transport without network access
+/// by looking a card up from a `MockServer` inside smol's executor.
+/// It expects the seeded card back.
+fn test_mock_server_smol() {
+    use scryfall_rs::{Card, MockServer};
+
+    let file =
+        std::fs::File::open("tests/data/black_lotus.json").expect("Failed to open test data");
+    let card: Card = serde_json::from_reader(file).expect("Failed to parse test data");
+    let server = MockServer::start(vec![card]).expect("Failed to start mock server");
+    smol::block_on(async {
+        let client = server.client();
+        let card = match client.card_named("Black Lotus").await {
+            Ok(card) => card,
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                panic!("Failed to fetch card named 'Black Lotus'");
+            }
+        };
+        assert_eq!(card.name, "Black Lotus");
+    });
+}
+
+#[cfg(all(not(feature = "tokio"), feature = "mock"))]
+#[test]
+/// This test checks the `ureq` transport without network access
+/// by looking a card up from a `MockServer` inside async-std's executor.
+/// It expects the seeded card back.
+fn test_mock_server_async_std() {
+    use scryfall_rs::{Card, MockServer};
+
+    let file =
+        std::fs::File::open("tests/data/black_lotus.json").expect("Failed to open test data");
+    let card: Card = serde_json::from_reader(file).expect("Failed to parse test data");
+    let server = MockServer::start(vec![card]).expect("Failed to start mock server");
+    async_std::task::block_on(async {
+        let client = server.client();
+        let card = match client.card_named("Black Lotus").await {
+            Ok(card) => card,
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                panic!("Failed to fetch card named 'Black Lotus'");
+            }
+        };
+        assert_eq!(card.name, "Black Lotus");
+    });
+}
@@ -0,0 +1,116 @@
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the functionality of the `search` function
+/// by running a query whose results span more than one page.
+/// It expects every matching card to be collected.
+async fn test_search_multiple_pages() {
+    use scryfall_rs::{ScryfallClient, SearchOptions};
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let results = match client.search("t:goblin", &SearchOptions::default()).await {
+        Ok(results) => results,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to search for goblins");
+        }
+    };
+    assert!(results.total_cards > 175);
+    assert_eq!(results.cards.len(), results.total_cards);
+}
+
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks that typed search options are sent to the Scryfall API.
+/// It expects every printing of a single card, sorted by release date.
+async fn test_search_options() {
+    use scryfall_rs::{ScryfallClient, SearchOptions, SortDirection, SortOrder, UniqueMode};
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let options = SearchOptions {
+        unique: Some(UniqueMode::Prints),
+        order: Some(SortOrder::Released),
+        dir: Some(SortDirection::Ascending),
+        ..Default::default()
+    };
+    let results = match client.search("!\"Black Lotus\"", &options).await {
+        Ok(results) => results,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to search for Black Lotus printings");
+        }
+    };
+    assert!(results.cards.len() > 1);
+    assert!(results.cards.iter().all(|card| card.name == "Black Lotus"));
+    assert_eq!(results.cards[0].set, "lea");
+}
+
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the error handling of the `search` function
+/// by running a query that matches nothing.
+/// It expects a 404 error response from the Scryfall API.
+async fn test_search_no_results() {
+    use scryfall_rs::{ScryfallClient, SearchOptions};
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let results = client
+        .search(
+            "t:goblin t:planeswalker o:zzzzzz",
+            &SearchOptions::default(),
+        )
+        .await;
+    let err = match results {
+        Ok(_) => panic!("Expected an error, but got results."),
+        Err(err) => err,
+    };
+    assert_eq!(err.status(), Some(404));
+}
+
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the functionality of the `search_stream` function
+/// by reading a few cards from a query with thousands of results.
+/// It expects cards to be yielded without fetching every page.
+async fn test_search_stream() {
+    use futures::{StreamExt, pin_mut};
+    use scryfall_rs::{ScryfallClient, SearchOptions};
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let options = SearchOptions::default();
+    let stream = client.search_stream("t:creature", &options).take(200);
+    pin_mut!(stream);
+    let mut count = 0;
+    while let Some(card) = stream.next().await {
+        match card {
+            Ok(card) => assert!(!card.name.is_empty()),
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                panic!("Failed to stream creature search results");
+            }
+        }
+        count += 1;
+    }
+    assert_eq!(count, 200);
+}
+
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the error handling of the `search_stream` function
+/// by streaming a query that matches nothing.
+/// It expects a single 404 error and then the end of the stream.
+async fn test_search_stream_error() {
+    use futures::StreamExt;
+    use scryfall_rs::{ScryfallClient, SearchOptions};
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let options = SearchOptions::default();
+    let results: Vec<_> = client
+        .search_stream("t:goblin t:planeswalker o:zzzzzz", &options)
+        .collect()
+        .await;
+    assert_eq!(results.len(), 1);
+    match &results[0] {
+        Ok(_) => panic!("Expected an error, but got a card."),
+        Err(err) => assert_eq!(err.status(), Some(404)),
+    }
+}
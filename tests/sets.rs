@@ -0,0 +1,103 @@
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the functionality of the `sets` function
+/// by requesting every set.
+/// It expects Limited Edition Alpha among the results.
+async fn test_sets() {
+    use scryfall_rs::ScryfallClient;
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let sets = match client.sets().await {
+        Ok(sets) => sets,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to fetch sets");
+        }
+    };
+    assert!(sets.len() > 500);
+    assert!(sets.iter().any(|set| set.code == "lea"));
+}
+
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the `set_by_code`, `set_by_id` and `set_by_tcgplayer_id`
+/// functions by resolving the same set three ways.
+/// It expects the same set every time.
+async fn test_set_lookups() {
+    use scryfall_rs::ScryfallClient;
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let set = match client.set_by_code("m21").await {
+        Ok(set) => set,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to fetch set m21");
+        }
+    };
+    assert_eq!(set.name, "Core Set 2021");
+    assert_eq!(set.released_at.as_deref(), Some("2020-07-03"));
+
+    let by_id = client
+        .set_by_id(&set.id)
+        .await
+        .expect("Failed to fetch set by id");
+    assert_eq!(by_id.code, "m21");
+
+    let tcgplayer_id = set.tcgplayer_id.expect("m21 has a TCGplayer id");
+    let by_tcgplayer = client
+        .set_by_tcgplayer_id(tcgplayer_id)
+        .await
+        .expect("Failed to fetch set by tcgplayer id");
+    assert_eq!(by_tcgplayer.code, "m21");
+}
+
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the error handling of the `set_by_code` function
+/// by requesting a set code that does not exist.
+/// It expects a 404 error response from the Scryfall API.
+async fn test_set_by_code_error() {
+    use scryfall_rs::ScryfallClient;
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let err = match client.set_by_code("zzzzz").await {
+        Ok(_) => panic!("Successfully fetched a set that does not exist"),
+        Err(err) => err,
+    };
+    assert_eq!(err.status(), Some(404));
+}
+
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks that `set_type` is decoded into the `SetType` enum
+/// for both sets and cards.
+/// It expects a memorabilia set and one of its cards to be recognised as such.
+async fn test_set_type() {
+    use scryfall_rs::{ScryfallClient, SetType};
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let set = client
+        .set_by_code("lea")
+        .await
+        .expect("Failed to fetch set lea");
+    assert_eq!(set.set_type, SetType::Core);
+
+    let card = client
+        .card_by_set_number("ced", "1", None)
+        .await
+        .expect("Failed to fetch CED #1");
+    assert_eq!(card.set_type, SetType::Memorabilia);
+}
+
+#[test]
+/// This test checks that set types Scryfall adds in the future still deserialize.
+/// It expects unrecognised values to round-trip through `SetType::Unknown`.
+fn test_set_type_unknown() {
+    use scryfall_rs::SetType;
+
+    let set_type: SetType = serde_json::from_str("\"treasure_chest\"").unwrap();
+    assert_eq!(set_type, SetType::TreasureChest);
+    let set_type: SetType = serde_json::from_str("\"mystery_box\"").unwrap();
+    assert_eq!(set_type, SetType::Unknown("mystery_box".to_string()));
+    assert_eq!(serde_json::to_string(&set_type).unwrap(), "\"mystery_box\"");
+}
@@ -0,0 +1,67 @@
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the functionality of the `symbology` function
+/// by requesting every card symbol.
+/// It expects well-known mana and tap symbols to be described.
+async fn test_symbology() {
+    use scryfall_rs::{Colors, ScryfallClient};
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let symbols = match client.symbology().await {
+        Ok(symbols) => symbols,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to fetch card symbology");
+        }
+    };
+    let tap = symbols
+        .iter()
+        .find(|symbol| symbol.symbol == "{T}")
+        .expect("{T} is a card symbol");
+    assert!(!tap.represents_mana);
+    let hybrid = symbols
+        .iter()
+        .find(|symbol| symbol.symbol == "{W/U}")
+        .expect("{W/U} is a card symbol");
+    assert!(hybrid.hybrid);
+    assert_eq!(hybrid.colors, vec![Colors::W, Colors::U]);
+}
+
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the functionality of the `parse_mana` function
+/// by normalizing a loosely written mana cost.
+/// It expects the canonical cost and its color information.
+async fn test_parse_mana() {
+    use scryfall_rs::ScryfallClient;
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let cost = match client.parse_mana("RUx").await {
+        Ok(cost) => cost,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to parse mana cost 'RUx'");
+        }
+    };
+    assert_eq!(cost.cost, "{X}{U}{R}");
+    assert_eq!(cost.cmc, 2.0);
+    assert!(cost.multicolored);
+    assert!(!cost.monocolored);
+    assert!(!cost.colorless);
+}
+
+#[tokio::test]
+#[ignore = "hits api.scryfall.com"]
+/// This test checks the error handling of the `parse_mana` function
+/// by parsing a string that is not a mana cost.
+/// It expects a 422 error response from the Scryfall API.
+async fn test_parse_mana_error() {
+    use scryfall_rs::ScryfallClient;
+
+    let client = ScryfallClient::new("scryfall-rs");
+    let err = match client.parse_mana("not a cost").await {
+        Ok(_) => panic!("Successfully parsed an invalid mana cost"),
+        Err(err) => err,
+    };
+    assert_eq!(err.status(), Some(422));
+}
@@ -0,0 +1,55 @@
+#[tokio::test]
+/// This test checks that `VcrTransport` replays what it recorded
+/// by recording a fixture response into a temporary directory.
+/// It expects the replayed card to match the recorded one.
+async fn test_vcr_round_trip() {
+    use scryfall_rs::{FixtureTransport, ScryfallClient, VcrTransport};
+
+    let dir = std::env::temp_dir().join(format!("scryfall-rs-vcr-{}", std::process::id()));
+    let body = std::fs::read("tests/data/black_lotus.json").expect("Failed to read test data");
+    let live = FixtureTransport::new().with_response("/cards/named?exact=Black+Lotus", 200, body);
+
+    let recorder = ScryfallClient::builder("scryfall-rs")
+        .transport(VcrTransport::record(&dir, live))
+        .build();
+    let recorded = match recorder.card_named("Black Lotus").await {
+        Ok(card) => card,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to record card named 'Black Lotus'");
+        }
+    };
+
+    let replayer = ScryfallClient::builder("scryfall-rs")
+        .transport(VcrTransport::replay(&dir))
+        .build();
+    let replayed = match replayer.card_named("Black Lotus").await {
+        Ok(card) => card,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            panic!("Failed to replay card named 'Black Lotus'");
+        }
+    };
+    std::fs::remove_dir_all(&dir).ok();
+    assert_eq!(recorded.id, replayed.id);
+}
+
+#[tokio::test]
+/// This test checks that replaying a request that was never recorded
+/// fails instead of reaching the network.
+/// It expects a transport error.
+async fn test_vcr_unrecorded_request() {
+    use scryfall_rs::{ScryfallClient, ScryfallClientError, VcrTransport};
+
+    let client = ScryfallClient::builder("scryfall-rs")
+        .transport(VcrTransport::replay("tests/fixtures/card_named"))
+        .build();
+    let err = match client.card_named("Shivan Dragon").await {
+        Ok(card) => panic!("Replayed a card that was never recorded: {}", card.name),
+        Err(err) => err,
+    };
+    assert!(
+        matches!(err, ScryfallClientError::Transport { .. }),
+        "{err:?}"
+    );
+}